@@ -0,0 +1,171 @@
+use std::path::PathBuf;
+use moonshade_raytracer::renderer::image::Image;
+use moonshade_raytracer::scene::camera::CameraLens;
+use moonshade_raytracer::scene::scene::Scene;
+
+const PREVIEW_DIVISOR: usize = 4;
+
+pub const USAGE: &str = "\
+Usage: moonshade-raytracer <scene> [options]
+
+Renders a scene file (see docs/scene_format.md) or a .gltf/.glb file to an image.
+
+Options:
+  -o, --output <path>       Output image, format from the extension: png, ppm, pbm, pnm [default: raytraced.pbm]
+  -r, --resolution <WxH>    Image resolution, overrides the scene
+  -s, --samples <count>     Samples per pixel, overrides the scene
+  -t, --threads <count>     Number of render threads
+  -l, --lens <kind>         Camera lens: perspective, thin_lens, orthographic, fisheye, sphere, panorama, cube_map
+      --fov <degrees>       Field of view for perspective, thin_lens and fisheye lenses
+  -c, --crop <x,y,w,h>      Only render the given pixel region of the full image
+  -p, --preview             Render at a quarter of the resolution with one sample per pixel
+  -h, --help                Print this help";
+
+pub enum Command {
+    Help,
+    Render(Box<Options>)
+}
+
+pub struct Options {
+    pub scene: PathBuf,
+    pub output: PathBuf,
+    pub resolution: Option<(usize, usize)>,
+    pub samples: Option<usize>,
+    pub threads: Option<usize>,
+    pub lens: Option<CameraLens>,
+    pub field_of_view: Option<f64>,
+    pub crop: Option<(usize, usize, usize, usize)>,
+    pub preview: bool
+}
+
+pub fn parse<I: Iterator<Item = String>>(mut arguments: I) -> Result<Command, String> {
+    let mut scene = None;
+    let mut options = Options {
+        scene: PathBuf::new(),
+        output: PathBuf::from("raytraced.pbm"),
+        resolution: None,
+        samples: None,
+        threads: None,
+        lens: None,
+        field_of_view: None,
+        crop: None,
+        preview: false
+    };
+
+    while let Some(argument) = arguments.next() {
+        let mut value = || arguments.next().ok_or_else(|| format!("'{}' expects a value", argument));
+
+        match argument.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-o" | "--output" => options.output = PathBuf::from(value()?),
+            "-r" | "--resolution" => options.resolution = Some(parse_resolution(&value()?)?),
+            "-s" | "--samples" => options.samples = Some(parse_count(&argument, &value()?)?),
+            "-t" | "--threads" => options.threads = Some(parse_count(&argument, &value()?)?),
+            "-l" | "--lens" => {
+                let kind = value()?;
+                options.lens = Some(CameraLens::named(&kind).ok_or_else(|| format!("unknown lens '{}'", kind))?);
+            }
+            "--fov" => options.field_of_view = Some(parse_angle(&value()?)?),
+            "-c" | "--crop" => options.crop = Some(parse_crop(&value()?)?),
+            "-p" | "--preview" => options.preview = true,
+            _ if argument.starts_with('-') => return Err(format!("unknown option '{}'", argument)),
+            _ if scene.is_none() => scene = Some(PathBuf::from(argument)),
+            _ => return Err(format!("unexpected argument '{}'", argument))
+        }
+    }
+
+    options.scene = scene.ok_or("missing scene file")?;
+
+    if !Image::can_save(&options.output) {
+        return Err(format!("cannot write '{}', use a .png, .ppm, .pbm or .pnm file", options.output.display()));
+    }
+
+    Ok(Command::Render(Box::new(options)))
+}
+
+pub fn run(options: Options) -> Result<(), String> {
+    let mut scene = Scene::load(&options.scene).map_err(|error| format!("{}: {}", options.scene.display(), error))?;
+
+    for warning in scene.warnings.iter() {
+        eprintln!("warning: {}", warning);
+    }
+
+    if let Some((width, height)) = options.resolution {
+        scene.width = width;
+        scene.height = height;
+    }
+
+    if let Some(samples) = options.samples {
+        scene.samples_per_pixel = samples;
+    }
+
+    if let Some(lens) = options.lens {
+        scene.camera.lens = lens;
+    }
+
+    if let Some(field_of_view) = options.field_of_view {
+        scene.camera.lens = scene.camera.lens.clone().with_field_of_view(field_of_view);
+    }
+
+    let mut crop = options.crop.unwrap_or((0, 0, scene.width, scene.height));
+
+    if options.preview {
+        scene.width = (scene.width / PREVIEW_DIVISOR).max(1);
+        scene.height = (scene.height / PREVIEW_DIVISOR).max(1);
+        scene.samples_per_pixel = 1;
+
+        crop = (crop.0 / PREVIEW_DIVISOR, crop.1 / PREVIEW_DIVISOR, (crop.2 / PREVIEW_DIVISOR).max(1), (crop.3 / PREVIEW_DIVISOR).max(1));
+    }
+
+    let (x, y, width, height) = crop;
+
+    if x + width > scene.width || y + height > scene.height {
+        return Err(format!("crop region {},{},{},{} lies outside the {}x{} image", x, y, width, height, scene.width, scene.height));
+    }
+
+    let mut renderer = scene.renderer();
+
+    if let Some(threads) = options.threads {
+        renderer.set_thread_count(threads);
+    }
+
+    let mut image: Image = Image::new(width, height);
+    renderer.render_region(&mut image, (scene.width, scene.height), (x, y), &scene.world());
+
+    image.save(&options.output).map_err(|error| format!("cannot write '{}': {}", options.output.display(), error))?;
+    eprintln!("wrote {} ({}x{})", options.output.display(), width, height);
+
+    Ok(())
+}
+
+fn parse_count(option: &str, value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(format!("'{}' expects a positive whole number, found '{}'", option, value))
+    }
+}
+
+fn parse_resolution(value: &str) -> Result<(usize, usize), String> {
+    let (width, height) = value.split_once(['x', 'X']).ok_or_else(|| format!("invalid resolution '{}', expected WIDTHxHEIGHT", value))?;
+
+    Ok((parse_count("--resolution", width)?, parse_count("--resolution", height)?))
+}
+
+fn parse_angle(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(degrees) if degrees > 0.0 && degrees <= 360.0 => Ok(degrees.to_radians()),
+        _ => Err(format!("invalid field of view '{}', expected degrees between 0 and 360", value))
+    }
+}
+
+fn parse_crop(value: &str) -> Result<(usize, usize, usize, usize), String> {
+    let parts: Vec<&str> = value.split(',').collect();
+
+    if parts.len() != 4 {
+        return Err(format!("invalid crop region '{}', expected X,Y,WIDTH,HEIGHT", value));
+    }
+
+    let offset = |part: &str| part.trim().parse::<usize>().map_err(|_| format!("invalid crop region '{}', expected X,Y,WIDTH,HEIGHT", value));
+
+    Ok((offset(parts[0])?, offset(parts[1])?, parse_count("--crop", parts[2].trim())?, parse_count("--crop", parts[3].trim())?))
+}
@@ -1,3 +1,7 @@
+#![allow(clippy::module_inception)]
+
 pub mod renderer;
 pub mod math;
-pub mod surfaces;
\ No newline at end of file
+pub mod surfaces;
+pub mod textures;
+pub mod scene;
\ No newline at end of file
@@ -1,100 +1,116 @@
 use std::ops::{Add, Div, Mul, Sub};
+use crate::math::float::Float;
 
 #[derive(Copy, Clone)]
-pub struct Color {
-    pub red: f64,
-    pub green: f64,
-    pub blue: f64,
-    pub alpha: f64
+pub struct Color<T: Float = f64> {
+    pub red: T,
+    pub green: T,
+    pub blue: T,
+    pub alpha: T
 }
 
-impl Color {
-    pub const WHITE: Color = Color { red: 1.0, green: 1.0, blue: 1.0, alpha: 1.0 };
-    pub const BLACK: Color = Color { red: 0.0, green: 0.0, blue: 0.0, alpha: 1.0 };
-    pub const RED: Color = Color { red: 1.0, green: 0.0, blue: 0.0, alpha: 1.0 };
-    pub const GREEN: Color = Color { red: 0.0, green: 1.0, blue: 0.0, alpha: 1.0 };
-    pub const BLUE: Color = Color { red: 0.0, green: 0.0, blue: 1.0, alpha: 1.0 };
-    pub const ZERO: Color = Color { red: 0.0, green: 0.0, blue: 0.0, alpha: 0.0 };
+impl<T: Float> Color<T> {
+    pub const WHITE: Color<T> = Color { red: T::ONE, green: T::ONE, blue: T::ONE, alpha: T::ONE };
+    pub const BLACK: Color<T> = Color { red: T::ZERO, green: T::ZERO, blue: T::ZERO, alpha: T::ONE };
+    pub const RED: Color<T> = Color { red: T::ONE, green: T::ZERO, blue: T::ZERO, alpha: T::ONE };
+    pub const GREEN: Color<T> = Color { red: T::ZERO, green: T::ONE, blue: T::ZERO, alpha: T::ONE };
+    pub const BLUE: Color<T> = Color { red: T::ZERO, green: T::ZERO, blue: T::ONE, alpha: T::ONE };
+    pub const ZERO: Color<T> = Color { red: T::ZERO, green: T::ZERO, blue: T::ZERO, alpha: T::ZERO };
 
-    pub fn new(red: f64, green: f64, blue: f64, alpha: f64) -> Color {
+    pub fn new(red: T, green: T, blue: T, alpha: T) -> Color<T> {
         Color { red: red.abs(), green: green.abs(), blue: blue.abs(), alpha: alpha.abs() }
     }
 
-    pub fn solid(red: f64, green: f64, blue: f64) -> Color {
-        Color { red, green, blue, alpha: 1.0 }
+    pub fn solid(red: T, green: T, blue: T) -> Color<T> {
+        Color { red, green, blue, alpha: T::ONE }
     }
 
-    pub fn with_alpha(color: Color, alpha: f64) -> Color {
+    pub fn cast<U: Float>(self) -> Color<U> {
+        Color { red: self.red.cast(), green: self.green.cast(), blue: self.blue.cast(), alpha: self.alpha.cast() }
+    }
+
+    pub fn with_alpha(color: Color<T>, alpha: T) -> Color<T> {
         Color::new(color.red, color.green, color.blue, alpha)
     }
 
-    pub fn grayscale(color: Color) -> Color {
-        let gray: f64 = (color.red + color.green + color.blue) / 3.0;
+    pub fn grayscale(color: Color<T>) -> Color<T> {
+        let gray: T = (color.red + color.green + color.blue) / T::from_f64(3.0);
         Color::new(gray, gray, gray, color.alpha)
     }
+
+    pub fn luminance(color: Color<T>) -> T {
+        T::from_f64(0.2126) * color.red + T::from_f64(0.7152) * color.green + T::from_f64(0.0722) * color.blue
+    }
 }
 
-impl Add<Color> for Color {
-    type Output = Color;
+impl<T: Float> Add<Color<T>> for Color<T> {
+    type Output = Color<T>;
 
-    fn add(self, rhs: Color) -> Self::Output {
+    fn add(self, rhs: Color<T>) -> Self::Output {
         Color::new(self.red + rhs.red, self.green + rhs.green, self.blue + rhs.blue, self.alpha + rhs.alpha)
     }
 }
 
-impl Sub<Color> for Color {
-    type Output = Color;
+impl<T: Float> Sub<Color<T>> for Color<T> {
+    type Output = Color<T>;
 
-    fn sub(self, rhs: Color) -> Self::Output {
+    fn sub(self, rhs: Color<T>) -> Self::Output {
         Color::new(self.red - rhs.red, self.green - rhs.green, self.blue - rhs.blue, self.alpha - rhs.alpha)
     }
 }
 
-impl Mul<Color> for Color {
-    type Output = Color;
+impl<T: Float> Mul<Color<T>> for Color<T> {
+    type Output = Color<T>;
 
-    fn mul(self, rhs: Color) -> Self::Output {
+    fn mul(self, rhs: Color<T>) -> Self::Output {
         Color::new(self.red * rhs.red, self.green * rhs.green, self.blue * rhs.blue, self.alpha * rhs.alpha)
     }
 }
 
-impl Mul<f64> for Color {
-    type Output = Color;
+impl<T: Float> Mul<T> for Color<T> {
+    type Output = Color<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Color::new(self.red * rhs, self.green * rhs, self.blue * rhs, self.alpha * rhs)
     }
 }
 
-impl Mul<Color> for f64 {
-    type Output = Color;
-
-    fn mul(self, rhs: Color) -> Self::Output {
-        Color::new(rhs.red * self, rhs.green * self, rhs.blue * self, rhs.alpha * self)
-    }
-}
+impl<T: Float> Div<Color<T>> for Color<T> {
+    type Output = Color<T>;
 
-impl Div<Color> for Color {
-    type Output = Color;
-
-    fn div(self, rhs: Color) -> Self::Output {
+    fn div(self, rhs: Color<T>) -> Self::Output {
         Color::new(self.red / rhs.red, self.green / rhs.green, self.blue / rhs.blue, self.alpha / rhs.alpha)
     }
 }
 
-impl Div<f64> for Color {
-    type Output = Color;
+impl<T: Float> Div<T> for Color<T> {
+    type Output = Color<T>;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        let factor: f64 = 1.0 / rhs;
+    fn div(self, rhs: T) -> Self::Output {
+        let factor: T = T::ONE / rhs;
         self * factor
     }
 }
 
-impl Div<Color> for f64 {
-    type Output = Color;
+macro_rules! impl_scalar_color_ops {
+    ($float: ident) => {
+        impl Mul<Color<$float>> for $float {
+            type Output = Color<$float>;
 
-    fn div(self, rhs: Color) -> Self::Output {
-        Color::new(self / rhs.red, self / rhs.green, self / rhs.blue, self / rhs.alpha)
-    }
-}
\ No newline at end of file
+            fn mul(self, rhs: Color<$float>) -> Self::Output {
+                Color::new(rhs.red * self, rhs.green * self, rhs.blue * self, rhs.alpha * self)
+            }
+        }
+
+        impl Div<Color<$float>> for $float {
+            type Output = Color<$float>;
+
+            fn div(self, rhs: Color<$float>) -> Self::Output {
+                Color::new(self / rhs.red, self / rhs.green, self / rhs.blue, self / rhs.alpha)
+            }
+        }
+    };
+}
+
+impl_scalar_color_ops!(f32);
+impl_scalar_color_ops!(f64);
\ No newline at end of file
@@ -0,0 +1,94 @@
+pub struct Distribution1D {
+    function: Vec<f64>,
+    cdf: Vec<f64>,
+    integral: f64
+}
+
+impl Distribution1D {
+    pub fn new(function: Vec<f64>) -> Distribution1D {
+        let count = function.len().max(1);
+        let mut cdf = Vec::with_capacity(count + 1);
+        cdf.push(0.0);
+
+        for value in function.iter() {
+            cdf.push(cdf[cdf.len() - 1] + value.abs() / count as f64);
+        }
+
+        let integral = cdf[cdf.len() - 1];
+
+        for (index, value) in cdf.iter_mut().enumerate() {
+            *value = if integral > 0.0 { *value / integral } else { index as f64 / count as f64 };
+        }
+
+        Distribution1D { function, cdf, integral }
+    }
+
+    pub fn len(&self) -> usize {
+        self.function.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.function.is_empty()
+    }
+
+    pub fn integral(&self) -> f64 {
+        self.integral
+    }
+
+    pub fn sample(&self, u: f64) -> (f64, f64, usize) {
+        if self.function.is_empty() {
+            return (u, 1.0, 0);
+        }
+
+        let index = self.cdf.partition_point(|value| *value <= u).clamp(1, self.function.len()) - 1;
+
+        let width = self.cdf[index + 1] - self.cdf[index];
+        let offset = if width > 0.0 { (u - self.cdf[index]) / width } else { 0.0 };
+
+        ((index as f64 + offset) / self.function.len() as f64, self.pdf(index), index)
+    }
+
+    pub fn pdf(&self, index: usize) -> f64 {
+        if self.integral > 0.0 {
+            self.function[index].abs() / self.integral
+        } else {
+            1.0
+        }
+    }
+}
+
+pub struct Distribution2D {
+    conditionals: Vec<Distribution1D>,
+    marginal: Distribution1D
+}
+
+impl Distribution2D {
+    pub fn new(function: &[f64], width: usize, height: usize) -> Distribution2D {
+        let conditionals: Vec<Distribution1D> = (0..height)
+            .map(|y| Distribution1D::new(function[y * width..(y + 1) * width].to_vec()))
+            .collect();
+
+        let marginal = Distribution1D::new(conditionals.iter().map(|row| row.integral()).collect());
+
+        Distribution2D { conditionals, marginal }
+    }
+
+    pub fn sample(&self, u: f64, v: f64) -> ((f64, f64), f64) {
+        let (y, marginal_pdf, row) = self.marginal.sample(v);
+        let (x, conditional_pdf, _) = self.conditionals[row].sample(u);
+
+        ((x, y), marginal_pdf * conditional_pdf)
+    }
+
+    pub fn pdf(&self, x: f64, y: f64) -> f64 {
+        let row = ((y * self.marginal.len() as f64) as usize).min(self.marginal.len().saturating_sub(1));
+        let conditional = &self.conditionals[row];
+        let column = ((x * conditional.len() as f64) as usize).min(conditional.len().saturating_sub(1));
+
+        if self.marginal.integral() <= 0.0 {
+            return 1.0;
+        }
+
+        conditional.function[column].abs() / self.marginal.integral()
+    }
+}
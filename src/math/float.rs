@@ -0,0 +1,98 @@
+use std::fmt::{Debug, Display};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub trait Float:
+    Copy + Default + PartialOrd + Debug + Display + Send + Sync + 'static
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+    + AddAssign + SubAssign + MulAssign + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const NEG_ONE: Self;
+    const TWO: Self;
+    const EPSILON: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn is_finite(self) -> bool;
+    fn next_up(self) -> Self;
+    fn next_down(self) -> Self;
+
+    fn cast<U: Float>(self) -> U {
+        U::from_f64(self.to_f64())
+    }
+
+    fn error_bound(n: u32) -> Self {
+        let n = Self::from_f64(n as f64) * Self::EPSILON / Self::TWO;
+        n / (Self::ONE - n)
+    }
+}
+
+macro_rules! impl_float {
+    ($float: ident) => {
+        impl Float for $float {
+            const ZERO: $float = 0.0;
+            const ONE: $float = 1.0;
+            const NEG_ONE: $float = -1.0;
+            const TWO: $float = 2.0;
+            const EPSILON: $float = $float::EPSILON;
+            const INFINITY: $float = $float::INFINITY;
+            const NEG_INFINITY: $float = $float::NEG_INFINITY;
+
+            fn from_f64(value: f64) -> $float {
+                value as $float
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn abs(self) -> $float {
+                $float::abs(self)
+            }
+
+            fn signum(self) -> $float {
+                $float::signum(self)
+            }
+
+            fn sqrt(self) -> $float {
+                $float::sqrt(self)
+            }
+
+            fn min(self, other: $float) -> $float {
+                $float::min(self, other)
+            }
+
+            fn max(self, other: $float) -> $float {
+                $float::max(self, other)
+            }
+
+            fn clamp(self, min: $float, max: $float) -> $float {
+                $float::clamp(self, min, max)
+            }
+
+            fn is_finite(self) -> bool {
+                $float::is_finite(self)
+            }
+
+            fn next_up(self) -> $float {
+                $float::next_up(self)
+            }
+
+            fn next_down(self) -> $float {
+                $float::next_down(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
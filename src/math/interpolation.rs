@@ -0,0 +1,87 @@
+use crate::math::color::Color;
+use crate::math::point::Point;
+use crate::math::transformation::transform::Transform;
+use crate::math::vector::Vector;
+
+const BEZIER_ITERATIONS: usize = 24;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Interpolation {
+    Step,
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    Bezier { x1: f64, y1: f64, x2: f64, y2: f64 }
+}
+
+impl Interpolation {
+    pub fn bezier(x1: f64, y1: f64, x2: f64, y2: f64) -> Interpolation {
+        Interpolation::Bezier { x1: x1.clamp(0.0, 1.0), y1, x2: x2.clamp(0.0, 1.0), y2 }
+    }
+
+    pub fn ease(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Interpolation::Step => if t < 1.0 { 0.0 } else { 1.0 },
+            Interpolation::Linear => t,
+            Interpolation::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Interpolation::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Interpolation::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Interpolation::Bezier { x1, y1, x2, y2 } => cubic_bezier(x1, y1, x2, y2, t)
+        }
+    }
+}
+
+fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, x: f64) -> f64 {
+    let curve = |a: f64, b: f64, s: f64| 3.0 * a * s * (1.0 - s) * (1.0 - s) + 3.0 * b * s * s * (1.0 - s) + s * s * s;
+
+    let (mut low, mut high) = (0.0, 1.0);
+
+    for _ in 0..BEZIER_ITERATIONS {
+        let middle = (low + high) / 2.0;
+
+        if curve(x1, x2, middle) < x {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    curve(y1, y2, (low + high) / 2.0)
+}
+
+pub trait Interpolate: Copy {
+    fn interpolate(a: Self, b: Self, t: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(a: f64, b: f64, t: f64) -> f64 {
+        a + (b - a) * t
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(a: Vector, b: Vector, t: f64) -> Vector {
+        a + (b - a) * t
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(a: Point, b: Point, t: f64) -> Point {
+        a + (b - a) * t
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(a: Color, b: Color, t: f64) -> Color {
+        a * (1.0 - t) + b * t
+    }
+}
+
+impl Interpolate for Transform {
+    fn interpolate(a: Transform, b: Transform, t: f64) -> Transform {
+        Transform::interpolate(a, b, t)
+    }
+}
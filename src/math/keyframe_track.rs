@@ -0,0 +1,72 @@
+use crate::math::interpolation::{Interpolate, Interpolation};
+
+#[derive(Copy, Clone)]
+pub struct Keyframe<T: Interpolate> {
+    pub time: f64,
+    pub value: T,
+    pub interpolation: Interpolation
+}
+
+impl<T: Interpolate> Keyframe<T> {
+    pub fn new(time: f64, value: T, interpolation: Interpolation) -> Keyframe<T> {
+        Keyframe { time, value, interpolation }
+    }
+
+    pub fn linear(time: f64, value: T) -> Keyframe<T> {
+        Keyframe::new(time, value, Interpolation::Linear)
+    }
+}
+
+#[derive(Clone)]
+pub struct KeyframeTrack<T: Interpolate> {
+    keyframes: Vec<Keyframe<T>>
+}
+
+impl<T: Interpolate> KeyframeTrack<T> {
+    pub fn new(mut keyframes: Vec<Keyframe<T>>) -> Option<KeyframeTrack<T>> {
+        if keyframes.is_empty() {
+            return None;
+        }
+
+        keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Some(KeyframeTrack { keyframes })
+    }
+
+    pub fn constant(value: T) -> KeyframeTrack<T> {
+        KeyframeTrack { keyframes: vec![Keyframe::linear(0.0, value)] }
+    }
+
+    pub fn insert(&mut self, keyframe: Keyframe<T>) {
+        let index = self.keyframes.partition_point(|existing| existing.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+    }
+
+    pub fn keyframes(&self) -> &[Keyframe<T>] {
+        &self.keyframes
+    }
+
+    pub fn start(&self) -> f64 {
+        self.keyframes[0].time
+    }
+
+    pub fn end(&self) -> f64 {
+        self.keyframes[self.keyframes.len() - 1].time
+    }
+
+    pub fn at(&self, time: f64) -> T {
+        let next = self.keyframes.partition_point(|keyframe| keyframe.time <= time);
+
+        if next == 0 {
+            return self.keyframes[0].value;
+        }
+
+        if next == self.keyframes.len() {
+            return self.keyframes[next - 1].value;
+        }
+
+        let (start, end) = (self.keyframes[next - 1], self.keyframes[next]);
+        let t = if end.time > start.time { (time - start.time) / (end.time - start.time) } else { 0.0 };
+
+        T::interpolate(start.value, end.value, start.interpolation.ease(t))
+    }
+}
@@ -1,25 +1,30 @@
 use std::ops::Mul;
+use crate::math::float::Float;
 use crate::math::point::Point;
 use crate::math::ray::Ray;
 use crate::math::vector::Vector;
 
-#[derive(Copy, Clone)]
-pub struct Matrix {
-    pub a: f64, pub b: f64, pub c: f64, pub d: f64,
-    pub e: f64, pub f: f64, pub g: f64, pub h: f64,
-    pub i: f64, pub j: f64, pub k: f64, pub l: f64,
-    pub m: f64, pub n: f64, pub o: f64, pub p: f64
+#[derive(Copy, Clone, PartialEq)]
+pub struct Matrix<T: Float = f64> {
+    pub a: T, pub b: T, pub c: T, pub d: T,
+    pub e: T, pub f: T, pub g: T, pub h: T,
+    pub i: T, pub j: T, pub k: T, pub l: T,
+    pub m: T, pub n: T, pub o: T, pub p: T
 }
 
-impl Matrix {
-    pub const IDENTITY: Matrix = Matrix {
-        a: 1.0, b: 0.0, c: 0.0, d: 0.0,
-        e: 0.0, f: 1.0, g: 0.0, h: 0.0,
-        i: 0.0, j: 0.0, k: 1.0, l: 0.0,
-        m: 0.0, n: 0.0, o: 0.0, p: 1.0
+impl<T: Float> Matrix<T> {
+    pub const IDENTITY: Matrix<T> = Matrix {
+        a: T::ONE, b: T::ZERO, c: T::ZERO, d: T::ZERO,
+        e: T::ZERO, f: T::ONE, g: T::ZERO, h: T::ZERO,
+        i: T::ZERO, j: T::ZERO, k: T::ONE, l: T::ZERO,
+        m: T::ZERO, n: T::ZERO, o: T::ZERO, p: T::ONE
     };
 
-    pub fn transposed(self) -> Matrix {
+    pub fn cast<U: Float>(self) -> Matrix<U> {
+        Matrix::from_rows(self.rows().map(|row| row.map(|value| value.cast())))
+    }
+
+    pub fn transposed(self) -> Matrix<T> {
         Matrix {
             a: self.a, b: self.e, c: self.i, d: self.m,
             e: self.b, f: self.f, g: self.j, h: self.n,
@@ -27,12 +32,110 @@ impl Matrix {
             m: self.d, n: self.h, o: self.l, p: self.p
         }
     }
+
+    pub fn from_rows(rows: [[T; 4]; 4]) -> Matrix<T> {
+        let [[a, b, c, d], [e, f, g, h], [i, j, k, l], [m, n, o, p]] = rows;
+        Matrix { a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p }
+    }
+
+    pub fn from_cols(cols: [[T; 4]; 4]) -> Matrix<T> {
+        Matrix::from_rows(cols).transposed()
+    }
+
+    pub fn rows(self) -> [[T; 4]; 4] {
+        [
+            [self.a, self.b, self.c, self.d],
+            [self.e, self.f, self.g, self.h],
+            [self.i, self.j, self.k, self.l],
+            [self.m, self.n, self.o, self.p]
+        ]
+    }
+
+    pub fn cols(self) -> [[T; 4]; 4] {
+        self.transposed().rows()
+    }
+
+    pub fn is_approximately(self, other: Matrix<T>, tolerance: T) -> bool {
+        self.rows().iter().flatten()
+            .zip(other.rows().iter().flatten())
+            .all(|(&a, &b)| (a - b).abs() <= tolerance)
+    }
+
+    pub fn determinant(self) -> T {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    pub fn inverse(self) -> Option<Matrix<T>> {
+        let ([s0, s1, s2, s3, s4, s5], [c0, c1, c2, c3, c4, c5]) = self.minors();
+        let determinant = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+
+        if determinant == T::ZERO || !determinant.is_finite() {
+            return None;
+        }
+
+        let inverse_determinant = T::ONE / determinant;
+
+        Some(Matrix {
+            a: (self.f * c5 - self.g * c4 + self.h * c3) * inverse_determinant,
+            b: (-self.b * c5 + self.c * c4 - self.d * c3) * inverse_determinant,
+            c: (self.n * s5 - self.o * s4 + self.p * s3) * inverse_determinant,
+            d: (-self.j * s5 + self.k * s4 - self.l * s3) * inverse_determinant,
+            e: (-self.e * c5 + self.g * c2 - self.h * c1) * inverse_determinant,
+            f: (self.a * c5 - self.c * c2 + self.d * c1) * inverse_determinant,
+            g: (-self.m * s5 + self.o * s2 - self.p * s1) * inverse_determinant,
+            h: (self.i * s5 - self.k * s2 + self.l * s1) * inverse_determinant,
+            i: (self.e * c4 - self.f * c2 + self.h * c0) * inverse_determinant,
+            j: (-self.a * c4 + self.b * c2 - self.d * c0) * inverse_determinant,
+            k: (self.m * s4 - self.n * s2 + self.p * s0) * inverse_determinant,
+            l: (-self.i * s4 + self.j * s2 - self.l * s0) * inverse_determinant,
+            m: (-self.e * c3 + self.f * c1 - self.g * c0) * inverse_determinant,
+            n: (self.a * c3 - self.b * c1 + self.c * c0) * inverse_determinant,
+            o: (-self.m * s3 + self.n * s1 - self.o * s0) * inverse_determinant,
+            p: (self.i * s3 - self.j * s1 + self.k * s0) * inverse_determinant
+        })
+    }
+
+    pub fn normal_matrix(self) -> Option<Matrix<T>> {
+        Some(self.inverse()?.transposed())
+    }
+
+    pub fn absolute(self) -> Matrix<T> {
+        Matrix::from_rows(self.rows().map(|row| row.map(|value| value.abs())))
+    }
+
+    pub fn point_error(self, point: Point<T>, error: Vector<T>) -> Vector<T> {
+        let absolute = self.absolute();
+        absolute * error * (T::ONE + T::error_bound(3)) + (absolute * point - Point::ZERO) * T::error_bound(3)
+    }
+
+    fn minors(self) -> ([T; 6], [T; 6]) {
+        let s = [
+            self.a * self.f - self.e * self.b,
+            self.a * self.g - self.e * self.c,
+            self.a * self.h - self.e * self.d,
+            self.b * self.g - self.f * self.c,
+            self.b * self.h - self.f * self.d,
+            self.c * self.h - self.g * self.d
+        ];
+
+        let c = [
+            self.i * self.n - self.m * self.j,
+            self.i * self.o - self.m * self.k,
+            self.i * self.p - self.m * self.l,
+            self.j * self.o - self.n * self.k,
+            self.j * self.p - self.n * self.l,
+            self.k * self.p - self.o * self.l
+        ];
+
+        (s, c)
+    }
 }
 
-impl Mul<Vector> for Matrix {
-    type Output = Vector;
+impl<T: Float> Mul<Vector<T>> for Matrix<T> {
+    type Output = Vector<T>;
 
-    fn mul(self, rhs: Vector) -> Self::Output {
+    fn mul(self, rhs: Vector<T>) -> Self::Output {
         Vector::new(
             self.a * rhs.x + self.b * rhs.y + self.c * rhs.z,
             self.e * rhs.x + self.f * rhs.y + self.g * rhs.z,
@@ -41,33 +144,34 @@ impl Mul<Vector> for Matrix {
     }
 }
 
-impl Mul<Point> for Matrix {
-    type Output = Point;
+impl<T: Float> Mul<Point<T>> for Matrix<T> {
+    type Output = Point<T>;
 
-    fn mul(self, rhs: Point) -> Self::Output {
+    fn mul(self, rhs: Point<T>) -> Self::Output {
         Point::new(
-            self.a * rhs.x + self.b * rhs.y + self.c * rhs.z + self.d * 1.0,
-            self.e * rhs.x + self.f * rhs.y + self.g * rhs.z + self.h * 1.0,
-            self.i * rhs.x + self.j * rhs.y + self.k * rhs.z + self.l * 1.0
+            self.a * rhs.x + self.b * rhs.y + self.c * rhs.z + self.d * T::ONE,
+            self.e * rhs.x + self.f * rhs.y + self.g * rhs.z + self.h * T::ONE,
+            self.i * rhs.x + self.j * rhs.y + self.k * rhs.z + self.l * T::ONE
         )
     }
 }
 
-impl Mul<Ray> for Matrix {
-    type Output = Ray;
+impl<T: Float> Mul<Ray<T>> for Matrix<T> {
+    type Output = Ray<T>;
 
-    fn mul(self, rhs: Ray) -> Self::Output {
-        Ray::new(
-            self * rhs.origin,
-            self * rhs.direction
-        )
+    fn mul(self, rhs: Ray<T>) -> Self::Output {
+        Ray {
+            origin: self * rhs.origin,
+            direction: self * rhs.direction,
+            ..rhs
+        }
     }
 }
 
-impl Mul<Matrix> for Matrix {
-    type Output = Matrix;
+impl<T: Float> Mul<Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
 
-    fn mul(self, rhs: Matrix) -> Self::Output {
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
         Matrix {
             a: self.a * rhs.a + self.b * rhs.e + self.c * rhs.i + self.d * rhs.m, b: self.a * rhs.b + self.b * rhs.f + self.c * rhs.j + self.d * rhs.n, c: self.a * rhs.c + self.b * rhs.g + self.c * rhs.k + self.d * rhs.o, d: self.a * rhs.d + self.b * rhs.h + self.c * rhs.l + self.d * rhs.p,
             e: self.e * rhs.a + self.f * rhs.e + self.g * rhs.i + self.h * rhs.m, f: self.e * rhs.b + self.f * rhs.f + self.g * rhs.j + self.h * rhs.n, g: self.e * rhs.c + self.f * rhs.g + self.g * rhs.k + self.h * rhs.o, h: self.e * rhs.d + self.f * rhs.h + self.g * rhs.l + self.h * rhs.p,
@@ -1,7 +1,14 @@
+pub mod float;
 pub mod vector;
 pub mod point;
 pub mod normal;
 pub mod color;
 pub mod ray;
+pub mod ray_packet;
 pub mod matrix;
-pub mod transformation;
\ No newline at end of file
+pub mod simd;
+pub mod transformation;
+pub mod sampling;
+pub mod distribution;
+pub mod interpolation;
+pub mod keyframe_track;
\ No newline at end of file
@@ -1,6 +1,103 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Mul, Neg};
+use crate::math::matrix::Matrix;
+use crate::math::vector::Vector;
+
 #[derive(Copy, Clone)]
 pub struct Normal {
     x: f64,
     y: f64,
     z: f64
-}
\ No newline at end of file
+}
+
+impl Normal {
+    pub const RIGHT: Normal = Normal { x: 1.0, y: 0.0, z: 0.0 };
+    pub const UP: Normal = Normal { x: 0.0, y: 1.0, z: 0.0 };
+    pub const FORWARD: Normal = Normal { x: 0.0, y: 0.0, z: 1.0 };
+
+    pub fn new(vector: Vector) -> Option<Normal> {
+        let vector = vector.normalized()?;
+        Some(Normal { x: vector.x, y: vector.y, z: vector.z })
+    }
+
+    pub fn x(self) -> f64 {
+        self.x
+    }
+
+    pub fn y(self) -> f64 {
+        self.y
+    }
+
+    pub fn z(self) -> f64 {
+        self.z
+    }
+
+    pub fn vector(self) -> Vector {
+        Vector::new(self.x, self.y, self.z)
+    }
+
+    pub fn dot(normal: Normal, vector: Vector) -> f64 {
+        Vector::dot(normal.vector(), vector)
+    }
+
+    pub fn transformed(self, inverse_matrix: Matrix) -> Option<Normal> {
+        Normal::new(inverse_matrix.transposed() * self.vector())
+    }
+
+    pub fn face_forward(self, towards: Vector) -> Normal {
+        if Normal::dot(self, towards) < 0.0 { -self } else { self }
+    }
+
+    pub fn frame(self) -> (Vector, Vector) {
+        let sign = 1.0_f64.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+
+        (
+            Vector::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Vector::new(b, sign + self.y * self.y * a, -self.y)
+        )
+    }
+
+    pub fn to_local(self, vector: Vector) -> Vector {
+        let (tangent, bitangent) = self.frame();
+        Vector::new(Vector::dot(vector, tangent), Vector::dot(vector, bitangent), Normal::dot(self, vector))
+    }
+
+    pub fn from_local(self, vector: Vector) -> Vector {
+        let (tangent, bitangent) = self.frame();
+        tangent * vector.x + bitangent * vector.y + self * vector.z
+    }
+}
+
+impl From<Normal> for Vector {
+    fn from(normal: Normal) -> Vector {
+        normal.vector()
+    }
+}
+
+impl Mul<f64> for Normal {
+    type Output = Vector;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.vector() * rhs
+    }
+}
+
+impl Neg for Normal {
+    type Output = Normal;
+
+    fn neg(self) -> Self::Output {
+        Normal {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z
+        }
+    }
+}
+
+impl Display for Normal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Normal ({}, {}, {})", self.x, self.y, self.z)
+    }
+}
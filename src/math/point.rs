@@ -1,134 +1,132 @@
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::math::float::Float;
 use crate::math::vector::Vector;
 
 #[derive(Copy, Clone, Default)]
-pub struct Point {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64
+pub struct Point<T: Float = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T
 }
 
-impl Point {
-    pub const ZERO: Point = Point { x: 0.0, y: 0.0, z: 0.0 };
+impl<T: Float> Point<T> {
+    pub const ZERO: Point<T> = Point { x: T::ZERO, y: T::ZERO, z: T::ZERO };
 
-    pub fn new(x: f64, y: f64, z: f64) -> Point {
+    pub fn new(x: T, y: T, z: T) -> Point<T> {
         Point { x, y, z }
     }
 
-    pub fn reflected(self, axis: Vector) -> Option<Point> {
+    pub fn cast<U: Float>(self) -> Point<U> {
+        Point { x: self.x.cast(), y: self.y.cast(), z: self.z.cast() }
+    }
+
+    pub fn reflected(self, axis: Vector<T>) -> Option<Point<T>> {
         if self.is_approximately_zero() {
             return None;
         }
 
-        Some(self - 2.0 * Vector::dot(self - Point::ZERO, axis) * axis)
+        Some(self - axis * (T::TWO * Vector::dot(self - Point::ZERO, axis)))
     }
 
-    pub fn distance(a: Point, b: Point) -> f64 {
+    pub fn distance(a: Point<T>, b: Point<T>) -> T {
         (b - a).length()
     }
 
-    pub fn distance_squared(a: Point, b: Point) -> f64 {
+    pub fn distance_squared(a: Point<T>, b: Point<T>) -> T {
         (b - a).length_squared()
     }
 
     pub fn is_approximately_zero(self) -> bool {
-        self.x.abs() < f64::EPSILON && self.y.abs() < f64::EPSILON && self.z.abs() < f64::EPSILON
+        self.x.abs() < T::EPSILON && self.y.abs() < T::EPSILON && self.z.abs() < T::EPSILON
     }
 }
 
-impl Add<Vector> for Point {
-    type Output = Point;
+impl<T: Float> Add<Vector<T>> for Point<T> {
+    type Output = Point<T>;
 
-    fn add(self, rhs: Vector) -> Self::Output {
+    fn add(self, rhs: Vector<T>) -> Self::Output {
         Point::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
     }
 }
 
-impl Add<Point> for Point {
-    type Output = Point;
+impl<T: Float> Add<Point<T>> for Point<T> {
+    type Output = Point<T>;
 
-    fn add(self, rhs: Point) -> Self::Output {
+    fn add(self, rhs: Point<T>) -> Self::Output {
         Point::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
     }
 }
 
-impl Sub<Vector> for Point {
-    type Output = Point;
+impl<T: Float> Sub<Vector<T>> for Point<T> {
+    type Output = Point<T>;
 
-    fn sub(self, rhs: Vector) -> Self::Output {
+    fn sub(self, rhs: Vector<T>) -> Self::Output {
         Point::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
     }
 }
 
-impl Sub<Point> for Point {
-    type Output = Vector;
+impl<T: Float> Sub<Point<T>> for Point<T> {
+    type Output = Vector<T>;
 
-    fn sub(self, rhs: Point) -> Self::Output {
+    fn sub(self, rhs: Point<T>) -> Self::Output {
         Vector::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
     }
 }
 
-impl Mul<f64> for Point {
-    type Output = Point;
+impl<T: Float> Mul<T> for Point<T> {
+    type Output = Point<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Point::new(self.x * rhs, self.y * rhs, self.z * rhs)
     }
 }
 
-impl Mul<Point> for f64 {
-    type Output = Point;
-
-    fn mul(self, rhs: Point) -> Self::Output {
-        Point::new(self * rhs.x, self * rhs.y, self * rhs.z)
-    }
-}
 
-impl Div<f64> for Point {
-    type Output = Point;
+impl<T: Float> Div<T> for Point<T> {
+    type Output = Point<T>;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        let factor: f64 = 1.0 / rhs;
+    fn div(self, rhs: T) -> Self::Output {
+        let factor: T = T::ONE / rhs;
         self * factor
     }
 }
 
-impl AddAssign<Point> for Point {
-    fn add_assign(&mut self, rhs: Point) {
+impl<T: Float> AddAssign<Point<T>> for Point<T> {
+    fn add_assign(&mut self, rhs: Point<T>) {
         self.x += rhs.x;
         self.y += rhs.y;
         self.z += rhs.z;
     }
 }
 
-impl AddAssign<Vector> for Point {
-    fn add_assign(&mut self, rhs: Vector) {
+impl<T: Float> AddAssign<Vector<T>> for Point<T> {
+    fn add_assign(&mut self, rhs: Vector<T>) {
         self.x += rhs.x;
         self.y += rhs.y;
         self.z += rhs.z;
     }
 }
 
-impl SubAssign<Vector> for Point {
-    fn sub_assign(&mut self, rhs: Vector) {
+impl<T: Float> SubAssign<Vector<T>> for Point<T> {
+    fn sub_assign(&mut self, rhs: Vector<T>) {
         self.x -= rhs.x;
         self.y -= rhs.y;
         self.z -= rhs.z;
     }
 }
 
-impl MulAssign<f64> for Point {
-    fn mul_assign(&mut self, rhs: f64) {
+impl<T: Float> MulAssign<T> for Point<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.x *= rhs;
         self.y *= rhs;
         self.z *= rhs;
     }
 }
 
-impl DivAssign<f64> for Point {
-    fn div_assign(&mut self, rhs: f64) {
-        let factor: f64 = 1.0 / rhs;
+impl<T: Float> DivAssign<T> for Point<T> {
+    fn div_assign(&mut self, rhs: T) {
+        let factor: T = T::ONE / rhs;
 
         self.x *= factor;
         self.y *= factor;
@@ -136,7 +134,22 @@ impl DivAssign<f64> for Point {
     }
 }
 
-impl Display for Point {
+macro_rules! impl_scalar_point_ops {
+    ($float: ident) => {
+        impl Mul<Point<$float>> for $float {
+            type Output = Point<$float>;
+
+            fn mul(self, rhs: Point<$float>) -> Self::Output {
+                Point::new(self * rhs.x, self * rhs.y, self * rhs.z)
+            }
+        }
+    };
+}
+
+impl_scalar_point_ops!(f32);
+impl_scalar_point_ops!(f64);
+
+impl<T: Float> Display for Point<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Point ({}, {}, {})", self.x, self.y, self.z)
     }
@@ -1,31 +1,52 @@
 use std::fmt::{Display, Formatter};
+use crate::math::float::Float;
 use crate::math::point::Point;
 use crate::math::vector::Vector;
 
 #[derive(Copy, Clone)]
-pub struct Ray {
-    pub origin: Point,
-    pub direction: Vector
+pub struct Ray<T: Float = f64> {
+    pub origin: Point<T>,
+    pub direction: Vector<T>,
+    pub time: T,
+    pub t_min: T,
+    pub t_max: T
 }
 
-impl Ray {
-    pub fn new(origin: Point, direction: Vector) -> Ray {
-        Ray { origin, direction }
+impl<T: Float> Ray<T> {
+    pub fn new(origin: Point<T>, direction: Vector<T>) -> Ray<T> {
+        Ray { origin, direction, time: T::ZERO, t_min: T::ZERO, t_max: T::INFINITY }
     }
 
-    pub fn from_points(a: Point, b: Point) -> Ray {
-        Ray { origin: a, direction: b - a }
+    pub fn timed(origin: Point<T>, direction: Vector<T>, time: T) -> Ray<T> {
+        Ray { origin, direction, time, t_min: T::ZERO, t_max: T::INFINITY }
     }
 
-    pub fn normalized(&self) -> Option<Ray> {
-        Some(Ray { origin: self.origin, direction: self.direction.normalized()? })
+    pub fn from_points(a: Point<T>, b: Point<T>) -> Ray<T> {
+        Ray { origin: a, direction: b - a, time: T::ZERO, t_min: T::ZERO, t_max: T::INFINITY }
     }
 
-    pub fn point_at(self, t: f64) -> Point {
+    pub fn cast<U: Float>(self) -> Ray<U> {
+        Ray { origin: self.origin.cast(), direction: self.direction.cast(), time: self.time.cast(), t_min: self.t_min.cast(), t_max: self.t_max.cast() }
+    }
+
+    pub fn with_time(self, time: T) -> Ray<T> {
+        Ray { time, ..self }
+    }
+
+    pub fn with_interval(self, t_min: T, t_max: T) -> Ray<T> {
+        Ray { t_min, t_max, ..self }
+    }
+
+    pub fn normalized(&self) -> Option<Ray<T>> {
+        let length = self.direction.length();
+        Some(Ray { direction: self.direction.normalized()?, t_min: self.t_min * length, t_max: self.t_max * length, ..*self })
+    }
+
+    pub fn point_at(self, t: T) -> Point<T> {
         self.origin + self.direction * t
     }
 
-    pub fn t_of_point(self, point: Point) -> Option<f64> {
+    pub fn t_of_point(self, point: Point<T>) -> Option<T> {
         if !self.contains(point) {
             return None;
         }
@@ -33,12 +54,12 @@ impl Ray {
         Some(Vector::dot(self.direction.inverse_or_zero(), point - self.origin))
     }
 
-    pub fn contains(self, point: Point) -> bool {
-        Vector::scalar_rejection(self.direction, point - self.origin) == 0.0
+    pub fn contains(self, point: Point<T>) -> bool {
+        Vector::scalar_rejection(self.direction, point - self.origin) == T::ZERO
     }
 }
 
-impl Display for Ray {
+impl<T: Float> Display for Ray<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Ray: Origin ({}, {}, {}), Direction: ({}, {}, {})", self.origin.x, self.origin.y, self.origin.z, self.direction.x, self.direction.y, self.direction.z)
     }
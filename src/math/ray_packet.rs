@@ -0,0 +1,70 @@
+use std::array;
+use std::ops::Mul;
+use crate::math::matrix::Matrix;
+use crate::math::point::Point;
+use crate::math::ray::Ray;
+use crate::math::simd::{Lanes, VectorPacket};
+
+#[derive(Copy, Clone)]
+pub struct RayPacket<const N: usize> {
+    pub origins: VectorPacket<N>,
+    pub directions: VectorPacket<N>,
+    pub times: Lanes<N>,
+    pub t_mins: Lanes<N>,
+    pub t_maxs: Lanes<N>
+}
+
+impl<const N: usize> RayPacket<N> {
+    pub fn new(rays: [Ray; N]) -> RayPacket<N> {
+        RayPacket {
+            origins: VectorPacket::from_fn(|lane| rays[lane].origin - Point::ZERO),
+            directions: VectorPacket::from_fn(|lane| rays[lane].direction),
+            times: Lanes::from_fn(|lane| rays[lane].time),
+            t_mins: Lanes::from_fn(|lane| rays[lane].t_min),
+            t_maxs: Lanes::from_fn(|lane| rays[lane].t_max)
+        }
+    }
+
+    pub fn padded(rays: &[Ray]) -> Option<RayPacket<N>> {
+        let last = *rays.last()?;
+        Some(RayPacket::new(array::from_fn(|lane| rays.get(lane).copied().unwrap_or(last))))
+    }
+
+    pub fn ray(&self, lane: usize) -> Ray {
+        Ray::timed(Point::ZERO + self.origins.vector(lane), self.directions.vector(lane), self.times.lane(lane))
+            .with_interval(self.t_mins.lane(lane), self.t_maxs.lane(lane))
+    }
+
+    pub fn rays(&self) -> [Ray; N] {
+        array::from_fn(|lane| self.ray(lane))
+    }
+
+    pub fn has_uniform_time(&self) -> bool {
+        self.times.0.iter().all(|&time| time == self.times.lane(0))
+    }
+}
+
+impl<const N: usize> Mul<RayPacket<N>> for Matrix {
+    type Output = RayPacket<N>;
+
+    fn mul(self, rhs: RayPacket<N>) -> Self::Output {
+        let (origins, directions) = (rhs.origins, rhs.directions);
+        let row = |a: f64, b: f64, c: f64, x: Lanes<N>, y: Lanes<N>, z: Lanes<N>| {
+            Lanes::splat(a) * x + Lanes::splat(b) * y + Lanes::splat(c) * z
+        };
+
+        RayPacket {
+            origins: VectorPacket {
+                x: row(self.a, self.b, self.c, origins.x, origins.y, origins.z) + Lanes::splat(self.d * 1.0),
+                y: row(self.e, self.f, self.g, origins.x, origins.y, origins.z) + Lanes::splat(self.h * 1.0),
+                z: row(self.i, self.j, self.k, origins.x, origins.y, origins.z) + Lanes::splat(self.l * 1.0)
+            },
+            directions: VectorPacket {
+                x: row(self.a, self.b, self.c, directions.x, directions.y, directions.z),
+                y: row(self.e, self.f, self.g, directions.x, directions.y, directions.z),
+                z: row(self.i, self.j, self.k, directions.x, directions.y, directions.z)
+            },
+            ..rhs
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use std::f64::consts::{PI, TAU};
+use crate::math::point::Point;
+use crate::math::vector::Vector;
+
+pub fn radical_inverse(mut index: u64) -> f64 {
+    index = index.rotate_right(32);
+    index = ((index & 0x0000ffff0000ffff) << 16) | ((index & 0xffff0000ffff0000) >> 16);
+    index = ((index & 0x00ff00ff00ff00ff) << 8) | ((index & 0xff00ff00ff00ff00) >> 8);
+    index = ((index & 0x0f0f0f0f0f0f0f0f) << 4) | ((index & 0xf0f0f0f0f0f0f0f0) >> 4);
+    index = ((index & 0x3333333333333333) << 2) | ((index & 0xcccccccccccccccc) >> 2);
+    index = ((index & 0x5555555555555555) << 1) | ((index & 0xaaaaaaaaaaaaaaaa) >> 1);
+
+    (index >> 11) as f64 / (1u64 << 53) as f64
+}
+
+pub fn radical_inverse_base(mut index: u64, base: u64) -> f64 {
+    let inverse_base = 1.0 / base as f64;
+    let (mut reversed, mut factor) = (0.0, inverse_base);
+
+    while index > 0 {
+        reversed += (index % base) as f64 * factor;
+        index /= base;
+        factor *= inverse_base;
+    }
+
+    reversed
+}
+
+pub fn hammersley(index: usize, count: usize) -> (f64, f64) {
+    ((index as f64 + 0.5) / count.max(1) as f64, radical_inverse(index as u64))
+}
+
+pub fn hash(value: u64) -> u64 {
+    let mut value = value.wrapping_add(0x9e3779b97f4a7c15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+    value ^ (value >> 31)
+}
+
+pub fn hash_to_unit(value: u64) -> f64 {
+    (hash(value) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+pub fn point_seed(point: Point) -> u64 {
+    hash(point.x.to_bits() ^ hash(point.y.to_bits() ^ hash(point.z.to_bits())))
+}
+
+pub fn rotated(sample: (f64, f64), offset: (f64, f64)) -> (f64, f64) {
+    ((sample.0 + offset.0).fract(), (sample.1 + offset.1).fract())
+}
+
+pub fn uniform_sphere(u: f64, v: f64) -> Vector {
+    let y = 1.0 - 2.0 * v;
+    let radius = (1.0 - y * y).max(0.0).sqrt();
+    let (sine, cosine) = (TAU * u).sin_cos();
+
+    Vector::new(radius * sine, y, radius * cosine)
+}
+
+pub fn uniform_sphere_pdf() -> f64 {
+    1.0 / (4.0 * PI)
+}
+
+pub fn concentric_disk(u: f64, v: f64) -> (f64, f64) {
+    let (a, b) = (2.0 * u - 1.0, 2.0 * v - 1.0);
+
+    if a == 0.0 && b == 0.0 {
+        return (0.0, 0.0);
+    }
+
+    let (radius, angle) = if a.abs() > b.abs() {
+        (a, PI / 4.0 * (b / a))
+    } else {
+        (b, PI / 2.0 - PI / 4.0 * (a / b))
+    };
+
+    (radius * angle.cos(), radius * angle.sin())
+}
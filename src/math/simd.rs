@@ -0,0 +1,292 @@
+use std::array;
+use std::cell::Cell;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::sync::OnceLock;
+use crate::math::vector::Vector;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum SimdLevel {
+    Scalar,
+    Sse,
+    Avx
+}
+
+thread_local! {
+    static LEVEL_LIMIT: Cell<Option<SimdLevel>> = const { Cell::new(None) };
+}
+
+impl SimdLevel {
+    pub fn detected() -> SimdLevel {
+        static LEVEL: OnceLock<SimdLevel> = OnceLock::new();
+        *LEVEL.get_or_init(SimdLevel::detect)
+    }
+
+    pub fn current() -> SimdLevel {
+        match LEVEL_LIMIT.get() {
+            Some(limit) => limit.min(SimdLevel::detected()),
+            _ => SimdLevel::detected()
+        }
+    }
+
+    pub fn limited<R, F: FnOnce() -> R>(limit: SimdLevel, f: F) -> R {
+        let previous = LEVEL_LIMIT.replace(Some(limit));
+        let result = f();
+        LEVEL_LIMIT.set(previous);
+        result
+    }
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    fn detect() -> SimdLevel {
+        if is_x86_feature_detected!("avx") {
+            SimdLevel::Avx
+        } else if is_x86_feature_detected!("sse4.1") {
+            SimdLevel::Sse
+        } else {
+            SimdLevel::Scalar
+        }
+    }
+
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    fn detect() -> SimdLevel {
+        SimdLevel::Scalar
+    }
+
+    pub fn packet_width(self) -> usize {
+        match self {
+            SimdLevel::Scalar => 1,
+            SimdLevel::Sse => 4,
+            SimdLevel::Avx => 8
+        }
+    }
+}
+
+pub trait Kernel {
+    type Output;
+
+    fn run(self) -> Self::Output;
+}
+
+pub fn dispatch<K: Kernel>(kernel: K) -> K::Output {
+    match SimdLevel::current() {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        // SAFETY: `current` never exceeds `detected`, which only reports AVX after `is_x86_feature_detected!("avx")`.
+        SimdLevel::Avx => unsafe { run_avx(kernel) },
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        // SAFETY: `current` never exceeds `detected`, which only reports SSE after `is_x86_feature_detected!("sse4.1")`.
+        SimdLevel::Sse => unsafe { run_sse(kernel) },
+        _ => kernel.run()
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[target_feature(enable = "avx")]
+fn run_avx<K: Kernel>(kernel: K) -> K::Output {
+    kernel.run()
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[target_feature(enable = "sse4.1")]
+fn run_sse<K: Kernel>(kernel: K) -> K::Output {
+    kernel.run()
+}
+
+#[derive(Copy, Clone)]
+#[repr(align(32))]
+pub struct Lanes<const N: usize>(pub [f64; N]);
+
+impl<const N: usize> Lanes<N> {
+    #[inline(always)]
+    pub fn splat(value: f64) -> Lanes<N> {
+        Lanes([value; N])
+    }
+
+    #[inline(always)]
+    pub fn from_fn<F: FnMut(usize) -> f64>(f: F) -> Lanes<N> {
+        Lanes(array::from_fn(f))
+    }
+
+    #[inline(always)]
+    pub fn map<F: Fn(f64) -> f64>(self, f: F) -> Lanes<N> {
+        Lanes(self.0.map(f))
+    }
+
+    #[inline(always)]
+    pub fn zip<F: Fn(f64, f64) -> f64>(self, other: Lanes<N>, f: F) -> Lanes<N> {
+        Lanes::from_fn(|lane| f(self.0[lane], other.0[lane]))
+    }
+
+    #[inline(always)]
+    pub fn lane(self, lane: usize) -> f64 {
+        self.0[lane]
+    }
+
+    #[inline(always)]
+    pub fn sqrt(self) -> Lanes<N> {
+        self.map(f64::sqrt)
+    }
+
+    #[inline(always)]
+    pub fn min(self, other: Lanes<N>) -> Lanes<N> {
+        self.zip(other, f64::min)
+    }
+
+    #[inline(always)]
+    pub fn max(self, other: Lanes<N>) -> Lanes<N> {
+        self.zip(other, f64::max)
+    }
+
+    #[inline(always)]
+    pub fn select(mask: [bool; N], a: Lanes<N>, b: Lanes<N>) -> Lanes<N> {
+        Lanes::from_fn(|lane| if mask[lane] { a.0[lane] } else { b.0[lane] })
+    }
+
+    #[inline(always)]
+    pub fn le(self, other: Lanes<N>) -> [bool; N] {
+        array::from_fn(|lane| self.0[lane] <= other.0[lane])
+    }
+}
+
+impl<const N: usize> Add<Lanes<N>> for Lanes<N> {
+    type Output = Lanes<N>;
+
+    #[inline(always)]
+    fn add(self, rhs: Lanes<N>) -> Self::Output {
+        self.zip(rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize> Sub<Lanes<N>> for Lanes<N> {
+    type Output = Lanes<N>;
+
+    #[inline(always)]
+    fn sub(self, rhs: Lanes<N>) -> Self::Output {
+        self.zip(rhs, |a, b| a - b)
+    }
+}
+
+impl<const N: usize> Mul<Lanes<N>> for Lanes<N> {
+    type Output = Lanes<N>;
+
+    #[inline(always)]
+    fn mul(self, rhs: Lanes<N>) -> Self::Output {
+        self.zip(rhs, |a, b| a * b)
+    }
+}
+
+impl<const N: usize> Div<Lanes<N>> for Lanes<N> {
+    type Output = Lanes<N>;
+
+    #[inline(always)]
+    fn div(self, rhs: Lanes<N>) -> Self::Output {
+        self.zip(rhs, |a, b| a / b)
+    }
+}
+
+impl<const N: usize> Neg for Lanes<N> {
+    type Output = Lanes<N>;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct VectorPacket<const N: usize> {
+    pub x: Lanes<N>,
+    pub y: Lanes<N>,
+    pub z: Lanes<N>
+}
+
+impl<const N: usize> VectorPacket<N> {
+    #[inline(always)]
+    pub fn splat(vector: Vector) -> VectorPacket<N> {
+        VectorPacket { x: Lanes::splat(vector.x), y: Lanes::splat(vector.y), z: Lanes::splat(vector.z) }
+    }
+
+    #[inline(always)]
+    pub fn from_fn<F: Fn(usize) -> Vector>(f: F) -> VectorPacket<N> {
+        let vectors: [Vector; N] = array::from_fn(f);
+        VectorPacket::from_vectors(vectors)
+    }
+
+    #[inline(always)]
+    pub fn from_vectors(vectors: [Vector; N]) -> VectorPacket<N> {
+        VectorPacket {
+            x: Lanes::from_fn(|lane| vectors[lane].x),
+            y: Lanes::from_fn(|lane| vectors[lane].y),
+            z: Lanes::from_fn(|lane| vectors[lane].z)
+        }
+    }
+
+    #[inline(always)]
+    pub fn vector(&self, lane: usize) -> Vector {
+        Vector::new(self.x.lane(lane), self.y.lane(lane), self.z.lane(lane))
+    }
+
+    #[inline(always)]
+    pub fn dot(a: VectorPacket<N>, b: VectorPacket<N>) -> Lanes<N> {
+        a.x * b.x + a.y * b.y + a.z * b.z
+    }
+
+    #[inline(always)]
+    pub fn cross(a: VectorPacket<N>, b: VectorPacket<N>) -> VectorPacket<N> {
+        VectorPacket {
+            x: a.y * b.z - a.z * b.y,
+            y: a.z * b.x - a.x * b.z,
+            z: a.x * b.y - a.y * b.x
+        }
+    }
+
+    #[inline(always)]
+    pub fn length_squared(self) -> Lanes<N> {
+        VectorPacket::dot(self, self)
+    }
+
+    #[inline(always)]
+    pub fn length(self) -> Lanes<N> {
+        self.length_squared().sqrt()
+    }
+
+    #[inline(always)]
+    pub fn reciprocal(self) -> VectorPacket<N> {
+        let one = Lanes::splat(1.0);
+        VectorPacket { x: one / self.x, y: one / self.y, z: one / self.z }
+    }
+}
+
+impl<const N: usize> Add<VectorPacket<N>> for VectorPacket<N> {
+    type Output = VectorPacket<N>;
+
+    #[inline(always)]
+    fn add(self, rhs: VectorPacket<N>) -> Self::Output {
+        VectorPacket { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    }
+}
+
+impl<const N: usize> Sub<VectorPacket<N>> for VectorPacket<N> {
+    type Output = VectorPacket<N>;
+
+    #[inline(always)]
+    fn sub(self, rhs: VectorPacket<N>) -> Self::Output {
+        VectorPacket { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+    }
+}
+
+impl<const N: usize> Mul<Lanes<N>> for VectorPacket<N> {
+    type Output = VectorPacket<N>;
+
+    #[inline(always)]
+    fn mul(self, rhs: Lanes<N>) -> Self::Output {
+        VectorPacket { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
+    }
+}
+
+impl<const N: usize> Neg for VectorPacket<N> {
+    type Output = VectorPacket<N>;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        VectorPacket { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
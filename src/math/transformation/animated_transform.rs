@@ -0,0 +1,43 @@
+use crate::math::interpolation::Interpolation;
+use crate::math::keyframe_track::{Keyframe, KeyframeTrack};
+use crate::math::transformation::transform::Transform;
+
+#[derive(Clone)]
+pub struct AnimatedTransform {
+    track: KeyframeTrack<Transform>
+}
+
+impl AnimatedTransform {
+    pub fn new(keyframes: Vec<(f64, Transform)>) -> Option<AnimatedTransform> {
+        let keyframes = keyframes.into_iter()
+            .map(|(time, transform)| Keyframe::linear(time, transform))
+            .collect();
+
+        Some(AnimatedTransform { track: KeyframeTrack::new(keyframes)? })
+    }
+
+    pub fn from_track(track: KeyframeTrack<Transform>) -> AnimatedTransform {
+        AnimatedTransform { track }
+    }
+
+    pub fn between(start: Transform, end: Transform) -> AnimatedTransform {
+        AnimatedTransform::eased(start, end, 0.0, 1.0, Interpolation::Linear)
+    }
+
+    pub fn eased(start: Transform, end: Transform, start_time: f64, end_time: f64, interpolation: Interpolation) -> AnimatedTransform {
+        let track = KeyframeTrack::new(vec![
+            Keyframe::new(start_time, start, interpolation),
+            Keyframe::linear(end_time, end)
+        ]);
+
+        AnimatedTransform { track: track.unwrap_or_else(|| KeyframeTrack::constant(start)) }
+    }
+
+    pub fn track(&self) -> &KeyframeTrack<Transform> {
+        &self.track
+    }
+
+    pub fn at(&self, time: f64) -> Transform {
+        self.track.at(time)
+    }
+}
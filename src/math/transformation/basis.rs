@@ -37,7 +37,9 @@ impl Basis {
         let u = match v {
             Vector { x: 0.0, y, z } => Vector::new(0.0, -z, y),
             Vector { x, y: 0.0, z } => Vector::new(-z, 0.0, x),
-            Vector { x, y, z } => Vector::new(-y, x, z)
+            Vector { x, y, z } if x.abs() <= y.abs() && x.abs() <= z.abs() => Vector::new(0.0, -z, y).normalized_or_zero(),
+            Vector { x, y, z } if y.abs() <= z.abs() => Vector::new(-z, 0.0, x).normalized_or_zero(),
+            Vector { x, y, .. } => Vector::new(-y, x, 0.0).normalized_or_zero()
         };
 
         let w = Vector::cross(u, v);
@@ -51,6 +53,7 @@ impl Basis {
         Some(Basis::basis_unchecked(u, v, w))
     }
 
+    #[allow(clippy::self_named_constructors)]
     pub fn basis(u: Vector, v: Vector, w: Vector) -> Option<Basis> {
         let determinant = {
             let right = u.x * v.y * w.z + u.y * v.z * w.x + u.z * v.x * w.y;
@@ -113,9 +116,10 @@ impl Mul<Ray> for Basis {
     type Output = Ray;
 
     fn mul(self, rhs: Ray) -> Self::Output {
-        Ray::new(
-            self * rhs.origin,
-            self * rhs.direction
-        )
+        Ray {
+            origin: self * rhs.origin,
+            direction: self * rhs.direction,
+            ..rhs
+        }
     }
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 pub mod basis;
+pub mod quaternion;
 pub mod rotation;
 pub mod scale;
 pub mod translation;
 pub mod transform;
+pub mod animated_transform;
 pub mod transformation;
\ No newline at end of file
@@ -0,0 +1,264 @@
+use std::ops::{Mul, Neg};
+use crate::math::matrix::Matrix;
+use crate::math::point::Point;
+use crate::math::ray::Ray;
+use crate::math::transformation::transformation::Transformation;
+use crate::math::vector::Vector;
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX
+}
+
+impl EulerOrder {
+    fn axes(self) -> (usize, usize, usize) {
+        match self {
+            EulerOrder::XYZ => (0, 1, 2),
+            EulerOrder::XZY => (0, 2, 1),
+            EulerOrder::YXZ => (1, 0, 2),
+            EulerOrder::YZX => (1, 2, 0),
+            EulerOrder::ZXY => (2, 0, 1),
+            EulerOrder::ZYX => (2, 1, 0)
+        }
+    }
+
+    fn parity(self) -> f64 {
+        match self {
+            EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY => 1.0,
+            _ => -1.0
+        }
+    }
+}
+
+impl Quaternion {
+    pub const IDENTITY: Quaternion = Quaternion { w: 1.0, x: 0.0, y: 0.0, z: 0.0 };
+
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Quaternion {
+        Quaternion { w, x, y, z }
+    }
+
+    pub fn from_axis_angle(axis: Vector, radians: f64) -> Quaternion {
+        let axis = match axis.normalized() {
+            Some(axis) => axis,
+            _ => return Quaternion::IDENTITY
+        };
+
+        let (sine, cosine) = (radians / 2.0).sin_cos();
+        Quaternion::new(cosine, axis.x * sine, axis.y * sine, axis.z * sine)
+    }
+
+    pub fn from_euler(order: EulerOrder, x: f64, y: f64, z: f64) -> Quaternion {
+        let rotations = [
+            Quaternion::from_axis_angle(Vector::RIGHT, x),
+            Quaternion::from_axis_angle(Vector::UP, y),
+            Quaternion::from_axis_angle(Vector::FORWARD, z)
+        ];
+
+        let (first, second, third) = order.axes();
+        rotations[third] * rotations[second] * rotations[first]
+    }
+
+    pub fn from_matrix(matrix: Matrix) -> Quaternion {
+        let trace = matrix.a + matrix.f + matrix.k;
+
+        let quaternion = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quaternion::new(s / 4.0, (matrix.j - matrix.g) / s, (matrix.c - matrix.i) / s, (matrix.e - matrix.b) / s)
+        } else if matrix.a > matrix.f && matrix.a > matrix.k {
+            let s = (1.0 + matrix.a - matrix.f - matrix.k).sqrt() * 2.0;
+            Quaternion::new((matrix.j - matrix.g) / s, s / 4.0, (matrix.b + matrix.e) / s, (matrix.c + matrix.i) / s)
+        } else if matrix.f > matrix.k {
+            let s = (1.0 + matrix.f - matrix.a - matrix.k).sqrt() * 2.0;
+            Quaternion::new((matrix.c - matrix.i) / s, (matrix.b + matrix.e) / s, s / 4.0, (matrix.g + matrix.j) / s)
+        } else {
+            let s = (1.0 + matrix.k - matrix.a - matrix.f).sqrt() * 2.0;
+            Quaternion::new((matrix.e - matrix.b) / s, (matrix.c + matrix.i) / s, (matrix.g + matrix.j) / s, s / 4.0)
+        };
+
+        quaternion.normalized().unwrap_or(Quaternion::IDENTITY)
+    }
+
+    pub fn axis_angle(self) -> (Vector, f64) {
+        let quaternion = if self.w < 0.0 { -self } else { self };
+        let axis = Vector::new(quaternion.x, quaternion.y, quaternion.z);
+
+        match axis.normalized() {
+            Some(normalized) => (normalized, 2.0 * axis.length().atan2(quaternion.w)),
+            _ => (Vector::UP, 0.0)
+        }
+    }
+
+    pub fn euler(self, order: EulerOrder) -> (f64, f64, f64) {
+        let rows = self.matrix().rows();
+        let (first, second, third) = order.axes();
+        let parity = order.parity();
+
+        let cosine = rows[third][third].hypot(rows[third][second]);
+        let middle = (-parity * rows[third][first]).atan2(cosine);
+
+        let (outer_first, outer_third) = if cosine > 1e-9 {
+            (
+                (parity * rows[third][second]).atan2(rows[third][third]),
+                (parity * rows[second][first]).atan2(rows[first][first])
+            )
+        } else {
+            ((-parity * rows[second][third]).atan2(rows[second][second]), 0.0)
+        };
+
+        let mut angles = [0.0; 3];
+        angles[first] = outer_first;
+        angles[second] = middle;
+        angles[third] = outer_third;
+
+        (angles[0], angles[1], angles[2])
+    }
+
+    pub fn dot(a: Quaternion, b: Quaternion) -> f64 {
+        a.w * b.w + a.x * b.x + a.y * b.y + a.z * b.z
+    }
+
+    pub fn length(self) -> f64 {
+        Quaternion::dot(self, self).sqrt()
+    }
+
+    pub fn normalized(self) -> Option<Quaternion> {
+        let length = self.length();
+
+        if length < f64::EPSILON || !length.is_finite() {
+            return None;
+        }
+
+        Some(self * (1.0 / length))
+    }
+
+    pub fn conjugate(self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn nlerp(a: Quaternion, b: Quaternion, t: f64) -> Quaternion {
+        let b = if Quaternion::dot(a, b) < 0.0 { -b } else { b };
+
+        Quaternion::new(
+            a.w + (b.w - a.w) * t,
+            a.x + (b.x - a.x) * t,
+            a.y + (b.y - a.y) * t,
+            a.z + (b.z - a.z) * t
+        ).normalized().unwrap_or(a)
+    }
+
+    pub fn slerp(a: Quaternion, b: Quaternion, t: f64) -> Quaternion {
+        let cosine = Quaternion::dot(a, b);
+        let (b, cosine) = if cosine < 0.0 { (-b, -cosine) } else { (b, cosine) };
+
+        if cosine > 1.0 - 1e-9 {
+            return Quaternion::nlerp(a, b, t);
+        }
+
+        let angle = cosine.acos();
+        let sine = angle.sin();
+        let (weight_a, weight_b) = (((1.0 - t) * angle).sin() / sine, (t * angle).sin() / sine);
+
+        Quaternion::new(
+            a.w * weight_a + b.w * weight_b,
+            a.x * weight_a + b.x * weight_b,
+            a.y * weight_a + b.y * weight_b,
+            a.z * weight_a + b.z * weight_b
+        )
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl Transformation for Quaternion {
+    fn inverse(&self) -> Self {
+        let length_squared = Quaternion::dot(*self, *self);
+
+        if length_squared < f64::EPSILON {
+            return Quaternion::IDENTITY;
+        }
+
+        self.conjugate() * (1.0 / length_squared)
+    }
+
+    fn matrix(&self) -> Matrix {
+        let Quaternion { w, x, y, z } = *self;
+
+        Matrix {
+            a: 1.0 - 2.0 * (y * y + z * z), b: 2.0 * (x * y - w * z),       c: 2.0 * (x * z + w * y),       d: 0.0,
+            e: 2.0 * (x * y + w * z),       f: 1.0 - 2.0 * (x * x + z * z), g: 2.0 * (y * z - w * x),       h: 0.0,
+            i: 2.0 * (x * z - w * y),       j: 2.0 * (y * z + w * x),       k: 1.0 - 2.0 * (x * x + y * y), l: 0.0,
+            m: 0.0,                         n: 0.0,                         o: 0.0,                         p: 1.0
+        }
+    }
+}
+
+impl Mul<f64> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Quaternion::new(self.w * rhs, self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Self::Output {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w
+        )
+    }
+}
+
+impl Mul<Vector> for Quaternion {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        let axis = Vector::new(self.x, self.y, self.z);
+        let t = Vector::cross(axis, rhs) * 2.0;
+
+        rhs + t * self.w + Vector::cross(axis, t)
+    }
+}
+
+impl Mul<Point> for Quaternion {
+    type Output = Point;
+
+    fn mul(self, rhs: Point) -> Self::Output {
+        Point::ZERO + self * (rhs - Point::ZERO)
+    }
+}
+
+impl Mul<Ray> for Quaternion {
+    type Output = Ray;
+
+    fn mul(self, rhs: Ray) -> Self::Output {
+        Ray {
+            origin: self * rhs.origin,
+            direction: self * rhs.direction,
+            ..rhs
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::math::matrix::Matrix;
 use crate::math::point::Point;
 use crate::math::ray::Ray;
 use crate::math::transformation::basis::Basis;
+use crate::math::transformation::quaternion::Quaternion;
 use crate::math::transformation::transformation::Transformation;
 use crate::math::vector::Vector;
 
@@ -64,6 +65,41 @@ impl Rotation {
             cosine
         }
     }
+
+    pub fn from_axis_angle(axis: Vector, radians: f64) -> Rotation {
+        let basis = match Basis::basis_from_up(axis) {
+            Some(basis) => basis,
+            _ => return Rotation::ZERO
+        };
+
+        let (sine, cosine) = radians.sin_cos();
+        Rotation { basis, sine, cosine }
+    }
+
+    pub fn from_matrix(matrix: Matrix) -> Rotation {
+        Rotation::from_quaternion(Quaternion::from_matrix(matrix))
+    }
+
+    pub fn from_quaternion(quaternion: Quaternion) -> Rotation {
+        let (axis, angle) = quaternion.axis_angle();
+        Rotation::from_axis_angle(axis, angle)
+    }
+
+    pub fn quaternion(&self) -> Quaternion {
+        Quaternion::from_matrix(self.matrix())
+    }
+
+    pub fn axis(&self) -> Vector {
+        self.basis.inverse() * Vector::UP
+    }
+
+    pub fn angle(&self) -> f64 {
+        self.sine.atan2(self.cosine)
+    }
+
+    pub fn slerp(a: Rotation, b: Rotation, t: f64) -> Rotation {
+        Rotation::from_quaternion(Quaternion::slerp(a.quaternion(), b.quaternion(), t))
+    }
 }
 
 impl Transformation for Rotation {
@@ -113,9 +149,10 @@ impl Mul<Ray> for Rotation {
     type Output = Ray;
 
     fn mul(self, rhs: Ray) -> Self::Output {
-        Ray::new(
-            self * rhs.origin,
-            self * rhs.direction
-        )
+        Ray {
+            origin: self * rhs.origin,
+            direction: self * rhs.direction,
+            ..rhs
+        }
     }
 }
\ No newline at end of file
@@ -20,6 +20,14 @@ impl Scale {
             ratio
         }
     }
+
+    pub fn ratio(&self) -> Vector {
+        self.ratio
+    }
+
+    pub fn lerp(a: Scale, b: Scale, t: f64) -> Scale {
+        Scale { ratio: a.ratio * (1.0 - t) + b.ratio * t }
+    }
 }
 
 impl Transformation for Scale {
@@ -59,9 +67,10 @@ impl Mul<Ray> for Scale {
     type Output = Ray;
 
     fn mul(self, rhs: Ray) -> Self::Output {
-        Ray::new(
-            self * rhs.origin,
-            self * rhs.direction
-        )
+        Ray {
+            origin: self * rhs.origin,
+            direction: self * rhs.direction,
+            ..rhs
+        }
     }
 }
\ No newline at end of file
@@ -1,8 +1,10 @@
 use std::ops::{Mul};
 use crate::math::matrix::Matrix;
+use crate::math::normal::Normal;
 use crate::math::point::Point;
 use crate::math::ray::Ray;
 use crate::math::transformation::basis::Basis;
+use crate::math::transformation::quaternion::Quaternion;
 use crate::math::transformation::rotation::Rotation;
 use crate::math::transformation::scale::Scale;
 use crate::math::transformation::transformation::Transformation;
@@ -11,46 +13,125 @@ use crate::math::vector::Vector;
 
 #[derive(Copy, Clone)]
 pub struct Transform {
-    basis: Basis,
-    translation: Translation,
-    rotation: Rotation,
-    scale: Scale
+    matrix: Matrix,
+    inverse_matrix: Matrix
 }
 
 impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        matrix: Matrix::IDENTITY,
+        inverse_matrix: Matrix::IDENTITY
+    };
+
     pub fn new(basis: Basis, translation: Translation, rotation: Rotation, scale: Scale) -> Transform {
         Transform {
-            basis,
-            translation,
-            rotation,
-            scale
+            matrix: basis.inverse().matrix() * translation.matrix() * rotation.matrix() * scale.matrix() * basis.matrix(),
+            inverse_matrix: basis.inverse().matrix() * scale.inverse().matrix() * rotation.inverse().matrix() * translation.inverse().matrix() * basis.matrix()
         }
     }
 
-    pub fn translation(translation: Vector) -> Transform {
+    pub fn from_parts(translation: Translation, rotation: Quaternion, scale: Scale) -> Transform {
         Transform {
-            basis: Basis::XYZ,
-            translation: Translation::new(translation),
-            rotation: Rotation::ZERO,
-            scale: Scale::ONE
+            matrix: translation.matrix() * rotation.matrix() * scale.matrix(),
+            inverse_matrix: scale.inverse().matrix() * rotation.inverse().matrix() * translation.inverse().matrix()
         }
     }
 
+    pub fn from_matrix(matrix: Matrix) -> Option<Transform> {
+        Some(Transform { matrix, inverse_matrix: matrix.inverse()? })
+    }
+
+    pub fn translation(translation: Vector) -> Transform {
+        Transform::from_parts(Translation::new(translation), Quaternion::IDENTITY, Scale::ONE)
+    }
+
     pub fn rotation(look: Vector) -> Transform {
-        Transform {
-            basis: Basis::XYZ,
-            translation: Translation::ZERO,
-            rotation: Rotation::look_towards(look).unwrap_or(Rotation::ZERO),
-            scale: Scale::ONE
-        }
+        let rotation = Rotation::look_towards(look).unwrap_or(Rotation::ZERO);
+        Transform::new(Basis::XYZ, Translation::ZERO, rotation, Scale::ONE)
+    }
+
+    pub fn orientation(quaternion: Quaternion) -> Transform {
+        Transform::from_parts(Translation::ZERO, quaternion, Scale::ONE)
     }
 
     pub fn scale(scale: Vector) -> Transform {
-        Transform {
-            basis: Basis::XYZ,
-            translation: Translation::ZERO,
-            rotation: Rotation::ZERO,
-            scale: Scale::new(scale)
+        Transform::from_parts(Translation::ZERO, Quaternion::IDENTITY, Scale::new(scale))
+    }
+
+    pub fn rotate_around(pivot: Point, rotation: Quaternion) -> Transform {
+        let offset = pivot - Point::ZERO;
+        Transform::translation(offset) * Transform::orientation(rotation) * Transform::translation(-offset)
+    }
+
+    pub fn scale_around(pivot: Point, scale: Vector) -> Transform {
+        let offset = pivot - Point::ZERO;
+        Transform::translation(offset) * Transform::scale(scale) * Transform::translation(-offset)
+    }
+
+    pub fn shear(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Option<Transform> {
+        Transform::from_matrix(Matrix {
+            a: 1.0, b: xy,  c: xz,  d: 0.0,
+            e: yx,  f: 1.0, g: yz,  h: 0.0,
+            i: zx,  j: zy,  k: 1.0, l: 0.0,
+            m: 0.0, n: 0.0, o: 0.0, p: 1.0
+        })
+    }
+
+    pub fn look_at(eye: Point, target: Point, up: Vector) -> Option<Transform> {
+        let forward = (target - eye).normalized()?;
+        let up = Vector::rejection(up, forward).normalized()?;
+        let right = Vector::cross(up, forward);
+
+        Transform::from_matrix(Matrix::from_cols([
+            [right.x, right.y, right.z, 0.0],
+            [-up.x, -up.y, -up.z, 0.0],
+            [forward.x, forward.y, forward.z, 0.0],
+            [eye.x, eye.y, eye.z, 1.0]
+        ]))
+    }
+
+    pub fn then(self, next: Transform) -> Transform {
+        next * self
+    }
+
+    pub fn inverse_matrix(&self) -> Matrix {
+        self.inverse_matrix
+    }
+
+    pub fn decompose(&self) -> Option<(Translation, Quaternion, Scale)> {
+        let matrix = self.matrix;
+        let columns = [
+            Vector::new(matrix.a, matrix.e, matrix.i),
+            Vector::new(matrix.b, matrix.f, matrix.j),
+            Vector::new(matrix.c, matrix.g, matrix.k)
+        ];
+
+        let sign = if matrix.determinant() < 0.0 { -1.0 } else { 1.0 };
+        let scale = Vector::new(columns[0].length() * sign, columns[1].length(), columns[2].length());
+
+        if scale.x.abs() < f64::EPSILON || scale.y.abs() < f64::EPSILON || scale.z.abs() < f64::EPSILON {
+            return None;
+        }
+
+        let (x, y, z) = (columns[0] / scale.x, columns[1] / scale.y, columns[2] / scale.z);
+        let rotation = Quaternion::from_matrix(Matrix {
+            a: x.x, b: y.x, c: z.x, d: 0.0,
+            e: x.y, f: y.y, g: z.y, h: 0.0,
+            i: x.z, j: y.z, k: z.z, l: 0.0,
+            ..Matrix::IDENTITY
+        });
+
+        Some((Translation::new(Vector::new(matrix.d, matrix.h, matrix.l)), rotation, Scale::new(scale)))
+    }
+
+    pub fn interpolate(a: Transform, b: Transform, t: f64) -> Transform {
+        match (a.decompose(), b.decompose()) {
+            (Some((translation_a, rotation_a, scale_a)), Some((translation_b, rotation_b, scale_b))) => Transform::from_parts(
+                Translation::lerp(translation_a, translation_b, t),
+                Quaternion::slerp(rotation_a, rotation_b, t),
+                Scale::lerp(scale_a, scale_b, t)
+            ),
+            _ => if t < 0.5 { a } else { b }
         }
     }
 }
@@ -58,15 +139,30 @@ impl Transform {
 impl Transformation for Transform {
     fn inverse(&self) -> Self {
         Transform {
-            basis: self.basis.inverse(),
-            translation: self.translation.inverse(),
-            rotation: self.rotation.inverse(),
-            scale: self.scale.inverse()
+            matrix: self.inverse_matrix,
+            inverse_matrix: self.matrix
         }
     }
 
     fn matrix(&self) -> Matrix {
-        self.basis.inverse().matrix() * self.translation.matrix() * self.rotation.matrix() * self.scale.matrix() * self.basis.matrix()
+        self.matrix
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Transform {
+        Transform::IDENTITY
+    }
+}
+
+impl Mul<Transform> for Transform {
+    type Output = Transform;
+
+    fn mul(self, rhs: Transform) -> Self::Output {
+        Transform {
+            matrix: self.matrix * rhs.matrix,
+            inverse_matrix: rhs.inverse_matrix * self.inverse_matrix
+        }
     }
 }
 
@@ -74,7 +170,15 @@ impl Mul<Vector> for Transform {
     type Output = Vector;
 
     fn mul(self, rhs: Vector) -> Self::Output {
-         self.basis.inverse() * (self.translation * (self.rotation * (self.scale * (self.basis * rhs))))
+        self.matrix * rhs
+    }
+}
+
+impl Mul<Normal> for Transform {
+    type Output = Option<Normal>;
+
+    fn mul(self, rhs: Normal) -> Self::Output {
+        rhs.transformed(self.inverse_matrix)
     }
 }
 
@@ -82,8 +186,7 @@ impl Mul<Point> for Transform {
     type Output = Point;
 
     fn mul(self, rhs: Point) -> Self::Output {
-        let rhs = rhs - Point::ZERO;
-        Point::ZERO + self * rhs
+        self.matrix * rhs
     }
 }
 
@@ -91,9 +194,6 @@ impl Mul<Ray> for Transform {
     type Output = Ray;
 
     fn mul(self, rhs: Ray) -> Self::Output {
-        Ray::new(
-            self * rhs.origin,
-            self * rhs.direction
-        )
+        self.matrix * rhs
     }
-}
\ No newline at end of file
+}
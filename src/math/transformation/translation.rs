@@ -18,6 +18,14 @@ impl Translation {
     pub fn new(displacement: Vector) -> Translation {
         Translation { displacement }
     }
+
+    pub fn displacement(&self) -> Vector {
+        self.displacement
+    }
+
+    pub fn lerp(a: Translation, b: Translation, t: f64) -> Translation {
+        Translation { displacement: a.displacement * (1.0 - t) + b.displacement * t }
+    }
 }
 
 impl Transformation for Translation {
@@ -48,6 +56,7 @@ impl Mul<Vector> for Translation {
 impl Mul<Point> for Translation {
     type Output = Point;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn mul(self, rhs: Point) -> Self::Output {
         rhs + self.displacement
     }
@@ -57,9 +66,10 @@ impl Mul<Ray> for Translation {
     type Output = Ray;
 
     fn mul(self, rhs: Ray) -> Self::Output {
-        Ray::new(
-            self * rhs.origin,
-            rhs.direction
-        )
+        Ray {
+            origin: self * rhs.origin,
+            direction: rhs.direction,
+            ..rhs
+        }
     }
 }
@@ -1,36 +1,41 @@
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::math::float::Float;
 
 #[derive(Copy, Clone, Default)]
-pub struct Vector {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64
+pub struct Vector<T: Float = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T
 }
 
-impl Vector {
-    pub const ZERO: Vector = Vector { x: 0.0, y: 0.0, z: 0.0 };
-    pub const ONE: Vector = Vector { x: 1.0, y: 1.0, z: 1.0 };
-    pub const RIGHT: Vector = Vector { x: 1.0, y: 0.0, z: 0.0 };
-    pub const LEFT: Vector = Vector { x: -1.0, y: 0.0, z: 0.0 };
-    pub const UP: Vector = Vector { x: 0.0, y: 1.0, z: 0.0 };
-    pub const DOWN: Vector = Vector { x: 0.0, y: -1.0, z: 0.0 };
-    pub const FORWARD: Vector = Vector { x: 0.0, y: 0.0, z: 1.0 };
-    pub const BACKWARD: Vector = Vector { x: 0.0, y: 0.0, z: -1.0 };
-
-    pub fn new(x: f64, y: f64, z: f64) -> Vector {
+impl<T: Float> Vector<T> {
+    pub const ZERO: Vector<T> = Vector { x: T::ZERO, y: T::ZERO, z: T::ZERO };
+    pub const ONE: Vector<T> = Vector { x: T::ONE, y: T::ONE, z: T::ONE };
+    pub const RIGHT: Vector<T> = Vector { x: T::ONE, y: T::ZERO, z: T::ZERO };
+    pub const LEFT: Vector<T> = Vector { x: T::NEG_ONE, y: T::ZERO, z: T::ZERO };
+    pub const UP: Vector<T> = Vector { x: T::ZERO, y: T::ONE, z: T::ZERO };
+    pub const DOWN: Vector<T> = Vector { x: T::ZERO, y: T::NEG_ONE, z: T::ZERO };
+    pub const FORWARD: Vector<T> = Vector { x: T::ZERO, y: T::ZERO, z: T::ONE };
+    pub const BACKWARD: Vector<T> = Vector { x: T::ZERO, y: T::ZERO, z: T::NEG_ONE };
+
+    pub fn new(x: T, y: T, z: T) -> Vector<T> {
         Vector { x, y, z }
     }
 
-    pub fn dot(a: Vector, b: Vector) -> f64 {
+    pub fn cast<U: Float>(self) -> Vector<U> {
+        Vector { x: self.x.cast(), y: self.y.cast(), z: self.z.cast() }
+    }
+
+    pub fn dot(a: Vector<T>, b: Vector<T>) -> T {
         a.x * b.x + a.y * b.y + a.z * b.z
     }
 
-    pub fn absolute_dot(a: Vector, b: Vector) -> f64 {
+    pub fn absolute_dot(a: Vector<T>, b: Vector<T>) -> T {
         Vector::dot(a, b).abs()
     }
 
-    pub fn cross(a: Vector, b: Vector) -> Vector {
+    pub fn cross(a: Vector<T>, b: Vector<T>) -> Vector<T> {
         Vector {
             x: a.y * b.z - a.z * b.y,
             y: a.z * b.x - a.x * b.z,
@@ -38,15 +43,15 @@ impl Vector {
         }
     }
 
-    pub fn sine(a: Vector, b: Vector) -> f64 {
+    pub fn sine(a: Vector<T>, b: Vector<T>) -> T {
         Vector::cross(a.normalized_or_zero(), b.normalized_or_zero()).length()
     }
 
-    pub fn cosine(a: Vector, b: Vector) -> f64 {
+    pub fn cosine(a: Vector<T>, b: Vector<T>) -> T {
         Vector::dot(a.normalized_or_zero(), b.normalized_or_zero())
     }
 
-    pub fn component_wise_multiplication(a: Vector, b: Vector) -> Vector {
+    pub fn component_wise_multiplication(a: Vector<T>, b: Vector<T>) -> Vector<T> {
         Vector {
             x: a.x * b.x,
             y: a.y * b.y,
@@ -54,35 +59,35 @@ impl Vector {
         }
     }
 
-    pub fn projection(a: Vector, onto: Vector) -> Vector {
-        Vector::dot(a, onto) * onto.inverse_or_zero()
+    pub fn projection(a: Vector<T>, onto: Vector<T>) -> Vector<T> {
+        onto.inverse_or_zero() * Vector::dot(a, onto)
     }
 
-    pub fn scalar_projection(a: Vector, onto: Vector) -> f64 {
+    pub fn scalar_projection(a: Vector<T>, onto: Vector<T>) -> T {
         Vector::dot(a, onto.normalized_or_zero())
     }
 
-    pub fn rejection(a: Vector, onto: Vector) -> Vector {
+    pub fn rejection(a: Vector<T>, onto: Vector<T>) -> Vector<T> {
         a - Vector::projection(a, onto)
     }
 
-    pub fn scalar_rejection(a: Vector, onto: Vector) -> f64 {
+    pub fn scalar_rejection(a: Vector<T>, onto: Vector<T>) -> T {
         Self::rejection(a, onto).length()
     }
 
-    pub fn reflection(a: Vector, axis: Vector) -> Vector {
-        a - 2.0 * Vector::projection(a, axis)
+    pub fn reflection(a: Vector<T>, axis: Vector<T>) -> Vector<T> {
+        a - Vector::projection(a, axis) * T::TWO
     }
 
-    pub fn length_squared(self) -> f64 {
+    pub fn length_squared(self) -> T {
         Vector::dot(self, self)
     }
 
-    pub fn length(self) -> f64 {
+    pub fn length(self) -> T {
         self.length_squared().sqrt()
     }
 
-    pub fn normalized(self) -> Option<Vector> {
+    pub fn normalized(self) -> Option<Vector<T>> {
         if self.is_approximately_zero() {
             return None;
         }
@@ -90,11 +95,11 @@ impl Vector {
         Some(self / self.length())
     }
 
-    pub fn normalized_or_zero(self) -> Vector {
+    pub fn normalized_or_zero(self) -> Vector<T> {
         self.normalized().unwrap_or(Vector::ZERO)
     }
 
-    pub fn inverse(self) -> Option<Vector> {
+    pub fn inverse(self) -> Option<Vector<T>> {
         if self.is_approximately_zero() {
             return None;
         }
@@ -102,74 +107,58 @@ impl Vector {
         Some(self / self.length_squared())
     }
 
-    pub fn inverse_or_zero(self) -> Vector {
+    pub fn inverse_or_zero(self) -> Vector<T> {
         self.inverse().unwrap_or(Vector::ZERO)
     }
 
-    pub fn absolute(self) -> Vector {
+    pub fn absolute(self) -> Vector<T> {
         Vector { x: self.x.abs(), y: self.y.abs(), z: self.z.abs() }
     }
 
-    pub fn sign(self) -> Vector {
+    pub fn sign(self) -> Vector<T> {
         Vector { x: self.x.signum(), y: self.y.signum(), z: self.z.signum() }
     }
 
     pub fn is_approximately_zero(self) -> bool {
-        self.x.abs() < f64::EPSILON && self.y.abs() < f64::EPSILON && self.z.abs() < f64::EPSILON
+        self.x.abs() < T::EPSILON && self.y.abs() < T::EPSILON && self.z.abs() < T::EPSILON
     }
 }
 
-impl Add<Vector> for Vector {
-    type Output = Vector;
+impl<T: Float> Add<Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
 
     fn add(self, rhs: Self) -> Self::Output {
         Vector::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
     }
 }
 
-impl Sub<Vector> for Vector {
-    type Output = Vector;
+impl<T: Float> Sub<Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn sub(self, rhs: Vector) -> Self::Output {
+    fn sub(self, rhs: Vector<T>) -> Self::Output {
         Vector::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
     }
 }
 
-impl Mul<f64> for Vector {
-    type Output = Vector;
+impl<T: Float> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Vector::new(self.x * rhs, self.y * rhs, self.z * rhs)
     }
 }
 
-impl Mul<Vector> for f64 {
-    type Output = Vector;
-
-    fn mul(self, rhs: Vector) -> Self::Output {
-        Vector::new(self * rhs.x, self * rhs.y, self * rhs.z)
-    }
-}
+impl<T: Float> Div<T> for Vector<T> {
+    type Output = Vector<T>;
 
-impl Div<f64> for Vector {
-    type Output = Vector;
-
-    fn div(self, rhs: f64) -> Self::Output {
-        let factor: f64 = 1.0 / rhs;
+    fn div(self, rhs: T) -> Self::Output {
+        let factor: T = T::ONE / rhs;
         self * factor
     }
 }
 
-impl Div<Vector> for f64 {
-    type Output = Vector;
-
-    fn div(self, rhs: Vector) -> Self::Output {
-        rhs.inverse_or_zero() * self
-    }
-}
-
-impl Neg for Vector {
-    type Output = Vector;
+impl<T: Float> Neg for Vector<T> {
+    type Output = Vector<T>;
 
     fn neg(self) -> Self::Output {
         Vector {
@@ -180,33 +169,33 @@ impl Neg for Vector {
     }
 }
 
-impl AddAssign<Vector> for Vector {
-    fn add_assign(&mut self, rhs: Vector) {
+impl<T: Float> AddAssign<Vector<T>> for Vector<T> {
+    fn add_assign(&mut self, rhs: Vector<T>) {
         self.x += rhs.x;
         self.y += rhs.y;
         self.z += rhs.z;
     }
 }
 
-impl SubAssign<Vector> for Vector {
-    fn sub_assign(&mut self, rhs: Vector) {
+impl<T: Float> SubAssign<Vector<T>> for Vector<T> {
+    fn sub_assign(&mut self, rhs: Vector<T>) {
         self.x -= rhs.x;
         self.y -= rhs.y;
         self.z -= rhs.z;
     }
 }
 
-impl MulAssign<f64> for Vector {
-    fn mul_assign(&mut self, rhs: f64) {
+impl<T: Float> MulAssign<T> for Vector<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.x *= rhs;
         self.y *= rhs;
         self.z *= rhs;
     }
 }
 
-impl DivAssign<f64> for Vector {
-    fn div_assign(&mut self, rhs: f64) {
-        let factor: f64 = 1.0 / rhs;
+impl<T: Float> DivAssign<T> for Vector<T> {
+    fn div_assign(&mut self, rhs: T) {
+        let factor: T = T::ONE / rhs;
 
         self.x *= factor;
         self.y *= factor;
@@ -214,7 +203,31 @@ impl DivAssign<f64> for Vector {
     }
 }
 
-impl Display for Vector {
+macro_rules! impl_scalar_vector_ops {
+    ($float: ident) => {
+        impl Mul<Vector<$float>> for $float {
+            type Output = Vector<$float>;
+
+            fn mul(self, rhs: Vector<$float>) -> Self::Output {
+                Vector::new(self * rhs.x, self * rhs.y, self * rhs.z)
+            }
+        }
+
+        impl Div<Vector<$float>> for $float {
+            type Output = Vector<$float>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: Vector<$float>) -> Self::Output {
+                rhs.inverse_or_zero() * self
+            }
+        }
+    };
+}
+
+impl_scalar_vector_ops!(f32);
+impl_scalar_vector_ops!(f64);
+
+impl<T: Float> Display for Vector<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Vector ({}, {}, {})", self.x, self.y, self.z)
     }
@@ -0,0 +1,62 @@
+use crate::math::color::Color;
+use crate::math::sampling;
+use crate::math::vector::Vector;
+
+pub mod constant_environment;
+pub mod gradient_environment;
+pub mod image_environment;
+pub mod sky_environment;
+
+pub trait Environment {
+    fn color(&self, direction: Vector) -> Color;
+
+    fn sample(&self, u: f64, v: f64) -> EnvironmentSample {
+        let direction = sampling::uniform_sphere(u, v);
+        EnvironmentSample { direction, color: self.color(direction), pdf: sampling::uniform_sphere_pdf() }
+    }
+
+    fn pdf(&self, _direction: Vector) -> f64 {
+        sampling::uniform_sphere_pdf()
+    }
+
+    fn directional_light(&self) -> Option<DirectionalLight> {
+        None
+    }
+}
+
+impl<E: Environment + ?Sized> Environment for Box<E> {
+    fn color(&self, direction: Vector) -> Color {
+        (**self).color(direction)
+    }
+
+    fn sample(&self, u: f64, v: f64) -> EnvironmentSample {
+        (**self).sample(u, v)
+    }
+
+    fn pdf(&self, direction: Vector) -> f64 {
+        (**self).pdf(direction)
+    }
+
+    fn directional_light(&self) -> Option<DirectionalLight> {
+        (**self).directional_light()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct EnvironmentSample {
+    pub direction: Vector,
+    pub color: Color,
+    pub pdf: f64
+}
+
+#[derive(Copy, Clone)]
+pub struct DirectionalLight {
+    pub direction: Vector,
+    pub color: Color
+}
+
+impl DirectionalLight {
+    pub fn new(direction: Vector, color: Color) -> DirectionalLight {
+        DirectionalLight { direction: direction.normalized_or_zero(), color }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::math::color::Color;
+use crate::math::vector::Vector;
+use crate::renderer::environment::Environment;
+
+pub struct ConstantEnvironment {
+    pub color: Color
+}
+
+impl ConstantEnvironment {
+    pub const BLACK: ConstantEnvironment = ConstantEnvironment { color: Color::BLACK };
+
+    pub fn new(color: Color) -> ConstantEnvironment {
+        ConstantEnvironment { color }
+    }
+}
+
+impl Environment for ConstantEnvironment {
+    fn color(&self, _direction: Vector) -> Color {
+        self.color
+    }
+}
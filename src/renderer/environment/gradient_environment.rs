@@ -0,0 +1,35 @@
+use crate::math::color::Color;
+use crate::math::vector::Vector;
+use crate::renderer::environment::Environment;
+
+pub struct GradientEnvironment {
+    pub zenith: Color,
+    pub horizon: Color,
+    pub ground: Color
+}
+
+impl GradientEnvironment {
+    pub fn new(zenith: Color, horizon: Color, ground: Color) -> GradientEnvironment {
+        GradientEnvironment { zenith, horizon, ground }
+    }
+
+    pub fn sky() -> GradientEnvironment {
+        GradientEnvironment {
+            zenith: Color::solid(0.25, 0.45, 0.9),
+            horizon: Color::solid(0.9, 0.95, 1.0),
+            ground: Color::solid(0.3, 0.27, 0.25)
+        }
+    }
+}
+
+impl Environment for GradientEnvironment {
+    fn color(&self, direction: Vector) -> Color {
+        let height = direction.normalized_or_zero().y;
+
+        if height < 0.0 {
+            return self.ground;
+        }
+
+        self.horizon * (1.0 - height) + self.zenith * height
+    }
+}
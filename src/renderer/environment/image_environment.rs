@@ -0,0 +1,102 @@
+use std::f64::consts::{PI, TAU};
+use std::path::{Path, PathBuf};
+use crate::math::color::Color;
+use crate::math::distribution::Distribution2D;
+use crate::math::vector::Vector;
+use crate::renderer::environment::{Environment, EnvironmentSample};
+use crate::renderer::image::Image;
+
+pub struct ImageEnvironment {
+    image: Image,
+    intensity: f64,
+    distribution: Distribution2D,
+    path: Option<PathBuf>
+}
+
+impl ImageEnvironment {
+    pub fn new(image: Image, intensity: f64) -> ImageEnvironment {
+        let (width, height) = image.resolution();
+
+        let weights: Vec<f64> = image.pixel_positions()
+            .map(|(x, y)| {
+                let sine = (PI * (y as f64 + 0.5) / height as f64).sin();
+                Color::luminance(image.color_at(x, y).unwrap_or(Color::BLACK)) * sine
+            })
+            .collect();
+
+        let distribution = Distribution2D::new(&weights, width, height);
+        ImageEnvironment { image, intensity, distribution, path: None }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P, intensity: f64) -> std::io::Result<ImageEnvironment> {
+        let image = Image::read(path.as_ref())?;
+        Ok(ImageEnvironment { path: Some(path.as_ref().to_path_buf()), ..ImageEnvironment::new(image, intensity) })
+    }
+
+    pub fn from_hdr_file<P: AsRef<Path>>(path: P, intensity: f64) -> std::io::Result<ImageEnvironment> {
+        let image = Image::read_hdr(path.as_ref())?;
+        Ok(ImageEnvironment { path: Some(path.as_ref().to_path_buf()), ..ImageEnvironment::new(image, intensity) })
+    }
+
+    pub fn intensity(&self) -> f64 {
+        self.intensity
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn direction_to_uv(direction: Vector) -> (f64, f64) {
+        let direction = direction.normalized_or_zero();
+        let u = 0.5 + direction.x.atan2(direction.z) / TAU;
+        let v = direction.y.clamp(-1.0, 1.0).acos() / PI;
+
+        (u, v)
+    }
+
+    pub fn uv_to_direction(u: f64, v: f64) -> Vector {
+        let (sine_phi, cosine_phi) = ((u - 0.5) * TAU).sin_cos();
+        let (sine_theta, cosine_theta) = (v * PI).sin_cos();
+
+        Vector::new(sine_theta * sine_phi, cosine_theta, sine_theta * cosine_phi)
+    }
+
+    fn color_at_uv(&self, u: f64, v: f64) -> Color {
+        let (width, height) = self.image.resolution();
+        let x = ((u * width as f64) as usize).min(width.saturating_sub(1));
+        let y = ((v * height as f64) as usize).min(height.saturating_sub(1));
+
+        self.image.color_at(x, y).unwrap_or(Color::BLACK) * self.intensity
+    }
+}
+
+impl Environment for ImageEnvironment {
+    fn color(&self, direction: Vector) -> Color {
+        let (u, v) = ImageEnvironment::direction_to_uv(direction);
+        self.color_at_uv(u, v)
+    }
+
+    fn sample(&self, u: f64, v: f64) -> EnvironmentSample {
+        let ((su, sv), uv_pdf) = self.distribution.sample(u, v);
+        let sine_theta = (sv * PI).sin();
+
+        let pdf = if sine_theta > 0.0 { uv_pdf / (2.0 * PI * PI * sine_theta) } else { 0.0 };
+
+        EnvironmentSample {
+            direction: ImageEnvironment::uv_to_direction(su, sv),
+            color: self.color_at_uv(su, sv),
+            pdf
+        }
+    }
+
+    fn pdf(&self, direction: Vector) -> f64 {
+        let (u, v) = ImageEnvironment::direction_to_uv(direction);
+        let sine_theta = (v * PI).sin();
+
+        if sine_theta <= 0.0 {
+            return 0.0;
+        }
+
+        self.distribution.pdf(u, v) / (2.0 * PI * PI * sine_theta)
+    }
+}
@@ -0,0 +1,196 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+use crate::math::color::Color;
+use crate::math::sampling;
+use crate::math::vector::Vector;
+use crate::renderer::environment::{DirectionalLight, Environment, EnvironmentSample};
+
+const SUN_ANGULAR_RADIUS: f64 = 0.004651;
+const SUN_ILLUMINANCE: f64 = 127.5;
+
+pub struct SkyEnvironment {
+    sun_direction: Vector,
+    sun_zenith: f64,
+    zenith: [f64; 3],
+    perez: [[f64; 5]; 3],
+    normalization: [f64; 3],
+    sun_irradiance: Color,
+    ground: Color,
+    turbidity: f64,
+    ground_albedo: Color,
+    intensity: f64
+}
+
+impl SkyEnvironment {
+    pub fn new(sun_direction: Vector, turbidity: f64, ground_albedo: Color, intensity: f64) -> SkyEnvironment {
+        let sun_direction = sun_direction.normalized().unwrap_or(Vector::UP);
+        let turbidity = turbidity.clamp(1.7, 10.0);
+        let sun_zenith = sun_direction.y.clamp(-1.0, 1.0).acos().min(FRAC_PI_2);
+
+        let perez = SkyEnvironment::perez_coefficients(turbidity);
+        let zenith = SkyEnvironment::zenith_values(turbidity, sun_zenith);
+        let normalization = [0, 1, 2].map(|channel| SkyEnvironment::perez(perez[channel], 0.0, sun_zenith));
+
+        let mut sky = SkyEnvironment {
+            sun_direction,
+            sun_zenith,
+            zenith,
+            perez,
+            normalization,
+            sun_irradiance: SkyEnvironment::sun_irradiance(turbidity, sun_zenith),
+            ground: Color::BLACK,
+            turbidity,
+            ground_albedo,
+            intensity
+        };
+
+        let sun_height = sun_direction.y.max(0.0);
+        let ambient = sky.sky_color(Vector::UP) * PI;
+        sky.ground = Color::with_alpha(ground_albedo * ((sky.sun_irradiance * sun_height * intensity + ambient) / PI), 1.0);
+
+        sky
+    }
+
+    fn perez_coefficients(turbidity: f64) -> [[f64; 5]; 3] {
+        [
+            [
+                -0.0193 * turbidity - 0.2592,
+                -0.0665 * turbidity + 0.0008,
+                -0.0004 * turbidity + 0.2125,
+                -0.0641 * turbidity - 0.8989,
+                -0.0033 * turbidity + 0.0452
+            ],
+            [
+                -0.0167 * turbidity - 0.2608,
+                -0.0950 * turbidity + 0.0092,
+                -0.0079 * turbidity + 0.2102,
+                -0.0441 * turbidity - 1.6537,
+                -0.0109 * turbidity + 0.0529
+            ],
+            [
+                0.1787 * turbidity - 1.4630,
+                -0.3554 * turbidity + 0.4275,
+                -0.0227 * turbidity + 5.3251,
+                0.1206 * turbidity - 2.5771,
+                -0.0670 * turbidity + 0.3703
+            ]
+        ]
+    }
+
+    fn zenith_values(turbidity: f64, sun_zenith: f64) -> [f64; 3] {
+        let (t, t2) = (turbidity, turbidity * turbidity);
+        let (s, s2, s3) = (sun_zenith, sun_zenith * sun_zenith, sun_zenith * sun_zenith * sun_zenith);
+
+        let x = t2 * (0.00166 * s3 - 0.00375 * s2 + 0.00209 * s)
+            + t * (-0.02903 * s3 + 0.06377 * s2 - 0.03202 * s + 0.00394)
+            + (0.11693 * s3 - 0.21196 * s2 + 0.06052 * s + 0.25886);
+
+        let y = t2 * (0.00275 * s3 - 0.00610 * s2 + 0.00317 * s)
+            + t * (-0.04214 * s3 + 0.08970 * s2 - 0.04153 * s + 0.00516)
+            + (0.15346 * s3 - 0.26756 * s2 + 0.06670 * s + 0.26688);
+
+        let chi = (4.0 / 9.0 - t / 120.0) * (PI - 2.0 * sun_zenith);
+        let luminance = ((4.0453 * t - 4.9710) * chi.tan() - 0.2155 * t + 2.4192).max(0.0);
+
+        [x, y, luminance]
+    }
+
+    fn perez(coefficients: [f64; 5], theta: f64, gamma: f64) -> f64 {
+        let [a, b, c, d, e] = coefficients;
+        let cosine_theta = theta.cos().max(0.001);
+        let cosine_gamma = gamma.cos();
+
+        (1.0 + a * (b / cosine_theta).exp()) * (1.0 + c * (d * gamma).exp() + e * cosine_gamma * cosine_gamma)
+    }
+
+    fn sun_irradiance(turbidity: f64, sun_zenith: f64) -> Color {
+        let zenith_degrees = sun_zenith.to_degrees();
+        let air_mass = 1.0 / (sun_zenith.cos() + 0.15 * (93.885 - zenith_degrees).max(0.001).powf(-1.253));
+        let beta = 0.04608 * turbidity - 0.04586;
+
+        let [red, green, blue] = [0.680, 0.550, 0.440].map(|wavelength: f64| {
+            let rayleigh = 0.008735 * wavelength.powf(-4.08);
+            let aerosol = beta * wavelength.powf(-1.3);
+            (-air_mass * (rayleigh + aerosol)).exp() * SUN_ILLUMINANCE
+        });
+
+        Color::solid(red, green, blue)
+    }
+
+    fn xyz_to_color(x: f64, y: f64, luminance: f64) -> Color {
+        if y <= 0.0 {
+            return Color::BLACK;
+        }
+
+        let big_x = x / y * luminance;
+        let big_z = (1.0 - x - y) / y * luminance;
+
+        Color::solid(
+            (3.2406 * big_x - 1.5372 * luminance - 0.4986 * big_z).max(0.0),
+            (-0.9689 * big_x + 1.8758 * luminance + 0.0415 * big_z).max(0.0),
+            (0.0557 * big_x - 0.2040 * luminance + 1.0570 * big_z).max(0.0)
+        )
+    }
+
+    fn sky_color(&self, direction: Vector) -> Color {
+        let direction = direction.normalized_or_zero();
+
+        if direction.y < 0.0 {
+            return self.ground;
+        }
+
+        let theta = direction.y.clamp(-1.0, 1.0).acos();
+        let gamma = Vector::dot(direction, self.sun_direction).clamp(-1.0, 1.0).acos();
+
+        let [x, y, luminance] = [0, 1, 2].map(|channel| {
+            self.zenith[channel] * SkyEnvironment::perez(self.perez[channel], theta, gamma) / self.normalization[channel]
+        });
+
+        SkyEnvironment::xyz_to_color(x, y, luminance) * self.intensity
+    }
+
+    pub fn sun_direction(&self) -> Vector {
+        self.sun_direction
+    }
+
+    pub fn sun_zenith(&self) -> f64 {
+        self.sun_zenith
+    }
+
+    pub fn turbidity(&self) -> f64 {
+        self.turbidity
+    }
+
+    pub fn ground_albedo(&self) -> Color {
+        self.ground_albedo
+    }
+
+    pub fn intensity(&self) -> f64 {
+        self.intensity
+    }
+}
+
+impl Environment for SkyEnvironment {
+    fn color(&self, direction: Vector) -> Color {
+        let sky = self.sky_color(direction);
+
+        if self.sun_direction.y <= 0.0 || Vector::cosine(direction, self.sun_direction) < SUN_ANGULAR_RADIUS.cos() {
+            return sky;
+        }
+
+        let solid_angle = PI * SUN_ANGULAR_RADIUS * SUN_ANGULAR_RADIUS;
+        sky + self.sun_irradiance * (self.intensity / solid_angle)
+    }
+
+    fn sample(&self, u: f64, v: f64) -> EnvironmentSample {
+        let direction = sampling::uniform_sphere(u, v);
+        EnvironmentSample { direction, color: self.sky_color(direction), pdf: sampling::uniform_sphere_pdf() }
+    }
+
+    fn directional_light(&self) -> Option<DirectionalLight> {
+        if self.sun_direction.y <= 0.0 {
+            return None;
+        }
+
+        Some(DirectionalLight::new(self.sun_direction, self.sun_irradiance * self.intensity))
+    }
+}
@@ -1,18 +1,64 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
 use crate::math::color::Color;
-pub struct Image {
+use crate::math::float::Float;
+
+pub mod radiance_hdr;
+pub mod portable_pixmap;
+pub mod portable_network_graphics;
+
+pub struct Image<T: Float = f64> {
     width: usize,
     height: usize,
-    pixels: Vec<Color>
+    pixels: Vec<Color<T>>
 }
 
 impl Image {
-    pub fn new(width: usize, height: usize) -> Image {
+    pub fn read<P: AsRef<Path>>(path: P) -> std::io::Result<Image> {
+        match Image::extension(path.as_ref()).as_deref() {
+            Some("hdr") => Image::read_hdr(path),
+            Some("ppm") | Some("pbm") | Some("pnm") => Image::read_ppm(path),
+            _ => Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "unsupported image format"))
+        }
+    }
+
+    pub fn read_hdr<P: AsRef<Path>>(path: P) -> std::io::Result<Image> {
+        radiance_hdr::decode(BufReader::new(File::open(path)?))
+    }
+
+    pub fn read_ppm<P: AsRef<Path>>(path: P) -> std::io::Result<Image> {
+        portable_pixmap::decode(BufReader::new(File::open(path)?))
+    }
+
+    pub fn can_save<P: AsRef<Path>>(path: P) -> bool {
+        matches!(Image::extension(path.as_ref()).as_deref(), Some("png") | Some("ppm") | Some("pbm") | Some("pnm"))
+    }
+
+    fn extension(path: &Path) -> Option<String> {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase())
+    }
+}
+
+impl<T: Float> Image<T> {
+    pub fn new(width: usize, height: usize) -> Image<T> {
         Image { width, height, pixels: vec![Color::BLACK; width * height] }
     }
 
+    pub fn from_pixels(width: usize, height: usize, pixels: Vec<Color<T>>) -> Image<T> {
+        assert_eq!(pixels.len(), width * height, "pixel count does not match the image resolution");
+        Image { width, height, pixels }
+    }
+
+    pub fn cast<U: Float>(&self) -> Image<U> {
+        Image { width: self.width, height: self.height, pixels: self.pixels.iter().map(|color| color.cast()).collect() }
+    }
+
     pub fn paint(&mut self, x: usize, y: usize, color: Color) {
         if self.is_coordinate_valid(x, y) {
-            self.pixels[x + y * self.width] = color;
+            self.pixels[x + y * self.width] = color.cast();
         }
     }
     
@@ -33,7 +79,7 @@ impl Image {
             return None;
         }
 
-        Some(self.pixels[x + y * self.width])
+        Some(self.pixels[x + y * self.width].cast())
     }
     
     pub fn width(&self) -> usize {
@@ -63,9 +109,10 @@ impl Image {
     pub fn pbm(&self) -> String {
         let colors: String = self.pixels.iter()
             .map(|color| {
-                let red = (color.red * 255.0) as usize;
-                let green = (color.green * 255.0) as usize;
-                let blue = (color.blue * 255.0) as usize;
+                let color: Color = color.cast();
+                let red = (color.red.clamp(0.0, 1.0) * 255.0) as usize;
+                let green = (color.green.clamp(0.0, 1.0) * 255.0) as usize;
+                let blue = (color.blue.clamp(0.0, 1.0) * 255.0) as usize;
 
                 format!("{} {} {}", red, green, blue)
             })
@@ -74,6 +121,22 @@ impl Image {
         
         format!("P3\n{} {}\n255\n{}", self.width, self.height, colors)
     }
+
+    pub fn save_pbm<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        File::create(path)?.write_all(self.pbm().as_bytes())
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        match Image::extension(path.as_ref()).as_deref() {
+            Some("png") => self.save_png(path),
+            Some("ppm") | Some("pbm") | Some("pnm") => self.save_pbm(path),
+            _ => Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "unsupported image format"))
+        }
+    }
+
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        portable_network_graphics::encode(self, BufWriter::new(File::create(path)?))
+    }
 }
 
 pub struct PixelPositionIterator {
@@ -96,7 +159,8 @@ impl Iterator for PixelPositionIterator {
             return None;
         }
 
+        let position = (self.x, self.y);
         self.x += 1;
-        Some((self.x, self.y))
+        Some(position)
     }
 }
\ No newline at end of file
@@ -0,0 +1,84 @@
+use std::io::{Result, Write};
+use crate::math::color::Color;
+use crate::math::float::Float;
+use crate::renderer::image::Image;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const MAXIMUM_STORED_BLOCK: usize = 65535;
+
+pub fn encode<T: Float, W: Write>(image: &Image<T>, mut writer: W) -> Result<()> {
+    let (width, height) = image.resolution();
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut scanlines = Vec::with_capacity(height * (width * 3 + 1));
+
+    for y in 0..height {
+        scanlines.push(0);
+
+        for x in 0..width {
+            let color = image.color_at(x, y).unwrap_or(Color::BLACK);
+            scanlines.push((color.red.clamp(0.0, 1.0) * 255.0) as u8);
+            scanlines.push((color.green.clamp(0.0, 1.0) * 255.0) as u8);
+            scanlines.push((color.blue.clamp(0.0, 1.0) * 255.0) as u8);
+        }
+    }
+
+    writer.write_all(&SIGNATURE)?;
+    write_chunk(&mut writer, b"IHDR", &header)?;
+    write_chunk(&mut writer, b"IDAT", &zlib_stored(&scanlines))?;
+    write_chunk(&mut writer, b"IEND", &[])
+}
+
+fn write_chunk<W: Write>(writer: &mut W, kind: &[u8; 4], data: &[u8]) -> Result<()> {
+    let crc = crc32_update(crc32_update(0xffffffff, kind), data);
+
+    writer.write_all(&(data.len() as u32).to_be_bytes())?;
+    writer.write_all(kind)?;
+    writer.write_all(data)?;
+    writer.write_all(&(crc ^ 0xffffffff).to_be_bytes())
+}
+
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(data.len() + data.len() / MAXIMUM_STORED_BLOCK * 5 + 11);
+    output.extend_from_slice(&[0x78, 0x01]);
+
+    let block_count = data.len().div_ceil(MAXIMUM_STORED_BLOCK).max(1);
+
+    for index in 0..block_count {
+        let block = &data[index * MAXIMUM_STORED_BLOCK..((index + 1) * MAXIMUM_STORED_BLOCK).min(data.len())];
+        let length = block.len() as u16;
+
+        output.push(if index + 1 == block_count { 1 } else { 0 });
+        output.extend_from_slice(&length.to_le_bytes());
+        output.extend_from_slice(&(!length).to_le_bytes());
+        output.extend_from_slice(block);
+    }
+
+    output.extend_from_slice(&adler32(data).to_be_bytes());
+    output
+}
+
+fn crc32_update(mut crc: u32, bytes: &[u8]) -> u32 {
+    for byte in bytes {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { 0xedb88320 ^ (crc >> 1) } else { crc >> 1 };
+        }
+    }
+
+    crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    (b << 16) | a
+}
@@ -0,0 +1,79 @@
+use std::io::{Error, ErrorKind, Read, Result};
+use crate::math::color::Color;
+use crate::renderer::image::Image;
+
+pub fn decode<R: Read>(mut reader: R) -> Result<Image> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+
+    let mut cursor = 0;
+    let magic = next_token(&data, &mut cursor)?;
+    let width = parse_number(next_token(&data, &mut cursor)?)?;
+    let height = parse_number(next_token(&data, &mut cursor)?)?;
+    let maximum = parse_number(next_token(&data, &mut cursor)?)?.max(1) as f64;
+
+    let samples: Vec<usize> = match magic {
+        b"P3" => (0..width * height * 3)
+            .map(|_| next_token(&data, &mut cursor).and_then(parse_number))
+            .collect::<Result<_>>()?,
+        b"P6" => {
+            let start = cursor + 1;
+            let bytes_per_sample = if maximum > 255.0 { 2 } else { 1 };
+            let end = start + width * height * 3 * bytes_per_sample;
+
+            if end > data.len() {
+                return Err(invalid("unexpected end of pixel data"));
+            }
+
+            data[start..end]
+                .chunks(bytes_per_sample)
+                .map(|sample| sample.iter().fold(0usize, |value, byte| value << 8 | *byte as usize))
+                .collect()
+        }
+        _ => return Err(invalid("unsupported portable pixmap format"))
+    };
+
+    let pixels = samples.chunks(3)
+        .map(|sample| Color::solid(sample[0] as f64 / maximum, sample[1] as f64 / maximum, sample[2] as f64 / maximum))
+        .collect();
+
+    Ok(Image::from_pixels(width, height, pixels))
+}
+
+fn next_token<'a>(data: &'a [u8], cursor: &mut usize) -> Result<&'a [u8]> {
+    loop {
+        while *cursor < data.len() && data[*cursor].is_ascii_whitespace() {
+            *cursor += 1;
+        }
+
+        if *cursor < data.len() && data[*cursor] == b'#' {
+            while *cursor < data.len() && data[*cursor] != b'\n' {
+                *cursor += 1;
+            }
+            continue;
+        }
+
+        break;
+    }
+
+    let start = *cursor;
+    while *cursor < data.len() && !data[*cursor].is_ascii_whitespace() {
+        *cursor += 1;
+    }
+
+    if start == *cursor {
+        return Err(invalid("unexpected end of file"));
+    }
+
+    Ok(&data[start..*cursor])
+}
+
+fn parse_number(token: &[u8]) -> Result<usize> {
+    std::str::from_utf8(token).ok()
+        .and_then(|token| token.parse().ok())
+        .ok_or_else(|| invalid("invalid number"))
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
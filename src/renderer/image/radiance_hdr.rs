@@ -0,0 +1,131 @@
+use std::io::{BufRead, Error, ErrorKind, Read, Result};
+use crate::math::color::Color;
+use crate::renderer::image::Image;
+
+pub fn decode<R: BufRead>(mut reader: R) -> Result<Image> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+
+    if !line.starts_with("#?") {
+        return Err(invalid("missing radiance header"));
+    }
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(invalid("unexpected end of header"));
+        }
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            break;
+        }
+
+        if let Some(format) = trimmed.strip_prefix("FORMAT=") {
+            if format != "32-bit_rle_rgbe" {
+                return Err(invalid("unsupported pixel format"));
+            }
+        }
+    }
+
+    line.clear();
+    reader.read_line(&mut line)?;
+    let (width, height) = parse_resolution(line.trim())?;
+
+    let mut pixels = Vec::with_capacity(width * height);
+    let mut scanline = vec![[0u8; 4]; width];
+
+    for _ in 0..height {
+        read_scanline(&mut reader, &mut scanline)?;
+        pixels.extend(scanline.iter().map(|rgbe| rgbe_to_color(*rgbe)));
+    }
+
+    Ok(Image::from_pixels(width, height, pixels))
+}
+
+fn parse_resolution(line: &str) -> Result<(usize, usize)> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+
+    match parts.as_slice() {
+        ["-Y", height, "+X", width] => {
+            let height = height.parse().map_err(|_| invalid("invalid image height"))?;
+            let width = width.parse().map_err(|_| invalid("invalid image width"))?;
+            Ok((width, height))
+        }
+        _ => Err(invalid("unsupported image orientation"))
+    }
+}
+
+fn read_scanline<R: Read>(reader: &mut R, scanline: &mut [[u8; 4]]) -> Result<()> {
+    let width = scanline.len();
+    let mut header = [0u8; 4];
+    reader.read_exact(&mut header)?;
+
+    let is_run_length_encoded = header[0] == 2 && header[1] == 2 && header[2] & 0x80 == 0
+        && (8..0x8000).contains(&width)
+        && ((header[2] as usize) << 8 | header[3] as usize) == width;
+
+    if !is_run_length_encoded {
+        scanline[0] = header;
+        for pixel in scanline.iter_mut().skip(1) {
+            reader.read_exact(pixel)?;
+        }
+
+        return Ok(());
+    }
+
+    for channel in 0..4 {
+        let mut x = 0;
+
+        while x < width {
+            let mut count = [0u8; 1];
+            reader.read_exact(&mut count)?;
+
+            if count[0] > 128 {
+                let run = (count[0] - 128) as usize;
+                let mut value = [0u8; 1];
+                reader.read_exact(&mut value)?;
+
+                if x + run > width {
+                    return Err(invalid("run length exceeds scanline"));
+                }
+
+                for pixel in scanline[x..x + run].iter_mut() {
+                    pixel[channel] = value[0];
+                }
+
+                x += run;
+            } else {
+                let run = count[0] as usize;
+
+                if run == 0 || x + run > width {
+                    return Err(invalid("invalid scanline run"));
+                }
+
+                let mut values = vec![0u8; run];
+                reader.read_exact(&mut values)?;
+
+                for (pixel, value) in scanline[x..x + run].iter_mut().zip(values) {
+                    pixel[channel] = value;
+                }
+
+                x += run;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn rgbe_to_color(rgbe: [u8; 4]) -> Color {
+    if rgbe[3] == 0 {
+        return Color::BLACK;
+    }
+
+    let factor = 2f64.powi(rgbe[3] as i32 - 136);
+    Color::solid(rgbe[0] as f64 * factor, rgbe[1] as f64 * factor, rgbe[2] as f64 * factor)
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
@@ -5,7 +5,29 @@ pub mod plane_perspective_lens_shader;
 pub mod sphere_lens_shader;
 pub mod panorama_perspective_lens_shader;
 pub mod panorama_orthographic_lens_shader;
+pub mod transformed_lens_shader;
+pub mod thin_lens_shader;
+pub mod aperture;
+pub mod field_of_view;
+pub mod fisheye_lens_shader;
+pub mod stereo_lens_shader;
+pub mod omni_directional_stereo_lens_shader;
+pub mod cube_map_lens_shader;
 
 pub trait LensShader {
-    fn ray_to_lens_point(&self, x: f64, y: f64) -> Option<Ray>;
+    fn ray_to_lens_point(&self, x: f64, y: f64, lens_x: f64, lens_y: f64) -> Option<Ray>;
+
+    fn ray_to_lens_point_at(&self, x: f64, y: f64, lens_x: f64, lens_y: f64, time: f64) -> Option<Ray> {
+        Some(self.ray_to_lens_point(x, y, lens_x, lens_y)?.with_time(time))
+    }
+}
+
+impl<L: LensShader + ?Sized> LensShader for Box<L> {
+    fn ray_to_lens_point(&self, x: f64, y: f64, lens_x: f64, lens_y: f64) -> Option<Ray> {
+        (**self).ray_to_lens_point(x, y, lens_x, lens_y)
+    }
+
+    fn ray_to_lens_point_at(&self, x: f64, y: f64, lens_x: f64, lens_y: f64, time: f64) -> Option<Ray> {
+        (**self).ray_to_lens_point_at(x, y, lens_x, lens_y, time)
+    }
 }
@@ -0,0 +1,52 @@
+use std::f64::consts::TAU;
+use std::sync::Arc;
+use crate::math::color::Color;
+use crate::math::distribution::Distribution2D;
+use crate::math::sampling;
+use crate::renderer::image::Image;
+
+#[derive(Clone)]
+pub enum Aperture {
+    Circular,
+    Polygonal { blades: usize, rotation: f64 },
+    Image(Arc<Distribution2D>)
+}
+
+impl Aperture {
+    pub fn image(image: &Image) -> Aperture {
+        let (width, height) = image.resolution();
+        let weights: Vec<f64> = image.pixel_positions()
+            .map(|(x, y)| Color::luminance(image.color_at(x, y).unwrap_or(Color::BLACK)))
+            .collect();
+
+        Aperture::Image(Arc::new(Distribution2D::new(&weights, width, height)))
+    }
+
+    pub fn sample(&self, u: f64, v: f64) -> (f64, f64) {
+        match self {
+            Aperture::Circular => sampling::concentric_disk(u, v),
+            Aperture::Polygonal { blades, rotation } => Aperture::sample_polygon((*blades).max(3), *rotation, u, v),
+            Aperture::Image(distribution) => {
+                let ((x, y), _) = distribution.sample(u, v);
+                (2.0 * x - 1.0, 2.0 * y - 1.0)
+            }
+        }
+    }
+
+    fn sample_polygon(blades: usize, rotation: f64, u: f64, v: f64) -> (f64, f64) {
+        let scaled = u * blades as f64;
+        let blade = (scaled as usize).min(blades - 1);
+        let u = scaled - blade as f64;
+
+        let corner = |index: usize| {
+            let (sine, cosine) = (rotation + TAU * index as f64 / blades as f64).sin_cos();
+            (cosine, sine)
+        };
+
+        let (a, b) = (corner(blade), corner(blade + 1));
+        let root = u.sqrt();
+        let (wa, wb) = (root * (1.0 - v), root * v);
+
+        (a.0 * wa + b.0 * wb, a.1 * wa + b.1 * wb)
+    }
+}
@@ -0,0 +1,180 @@
+use std::io::Result;
+use std::path::Path;
+use crate::math::color::Color;
+use crate::math::float::Float;
+use crate::math::point::Point;
+use crate::math::ray::Ray;
+use crate::math::vector::Vector;
+use crate::renderer::image::Image;
+use crate::renderer::lens_shader::LensShader;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CubeFace {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ
+}
+
+impl CubeFace {
+    pub const ALL: [CubeFace; 6] = [
+        CubeFace::PositiveX,
+        CubeFace::NegativeX,
+        CubeFace::PositiveY,
+        CubeFace::NegativeY,
+        CubeFace::PositiveZ,
+        CubeFace::NegativeZ
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CubeFace::PositiveX => "posx",
+            CubeFace::NegativeX => "negx",
+            CubeFace::PositiveY => "posy",
+            CubeFace::NegativeY => "negy",
+            CubeFace::PositiveZ => "posz",
+            CubeFace::NegativeZ => "negz"
+        }
+    }
+
+    pub fn direction(self, s: f64, t: f64) -> Vector {
+        let (sc, tc) = (2.0 * s - 1.0, 2.0 * t - 1.0);
+
+        match self {
+            CubeFace::PositiveX => Vector::new(1.0, -tc, -sc),
+            CubeFace::NegativeX => Vector::new(-1.0, -tc, sc),
+            CubeFace::PositiveY => Vector::new(sc, 1.0, tc),
+            CubeFace::NegativeY => Vector::new(sc, -1.0, -tc),
+            CubeFace::PositiveZ => Vector::new(sc, -tc, 1.0),
+            CubeFace::NegativeZ => Vector::new(-sc, -tc, -1.0)
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CubeMapLayout {
+    HorizontalStrip,
+    VerticalStrip,
+    HorizontalCross,
+    VerticalCross
+}
+
+impl CubeMapLayout {
+    pub fn grid(self) -> (usize, usize) {
+        match self {
+            CubeMapLayout::HorizontalStrip => (6, 1),
+            CubeMapLayout::VerticalStrip => (1, 6),
+            CubeMapLayout::HorizontalCross => (4, 3),
+            CubeMapLayout::VerticalCross => (3, 4)
+        }
+    }
+
+    pub fn resolution(self, face_size: usize) -> (usize, usize) {
+        let (columns, rows) = self.grid();
+        (columns * face_size, rows * face_size)
+    }
+
+    pub fn face_at(self, column: usize, row: usize) -> Option<(CubeFace, bool)> {
+        let face = match (self, column, row) {
+            (CubeMapLayout::HorizontalStrip, index, 0) => *CubeFace::ALL.get(index)?,
+            (CubeMapLayout::VerticalStrip, 0, index) => *CubeFace::ALL.get(index)?,
+            (CubeMapLayout::HorizontalCross, 1, 0) => CubeFace::PositiveY,
+            (CubeMapLayout::HorizontalCross, 0, 1) => CubeFace::NegativeX,
+            (CubeMapLayout::HorizontalCross, 1, 1) => CubeFace::PositiveZ,
+            (CubeMapLayout::HorizontalCross, 2, 1) => CubeFace::PositiveX,
+            (CubeMapLayout::HorizontalCross, 3, 1) => CubeFace::NegativeZ,
+            (CubeMapLayout::HorizontalCross, 1, 2) => CubeFace::NegativeY,
+            (CubeMapLayout::VerticalCross, 1, 0) => CubeFace::PositiveY,
+            (CubeMapLayout::VerticalCross, 0, 1) => CubeFace::NegativeX,
+            (CubeMapLayout::VerticalCross, 1, 1) => CubeFace::PositiveZ,
+            (CubeMapLayout::VerticalCross, 2, 1) => CubeFace::PositiveX,
+            (CubeMapLayout::VerticalCross, 1, 2) => CubeFace::NegativeY,
+            (CubeMapLayout::VerticalCross, 1, 3) => return Some((CubeFace::NegativeZ, true)),
+            _ => return None
+        };
+
+        Some((face, false))
+    }
+
+    pub fn cell_of(self, face: CubeFace) -> (usize, usize, bool) {
+        let (columns, rows) = self.grid();
+
+        (0..rows)
+            .flat_map(|row| (0..columns).map(move |column| (column, row)))
+            .find_map(|(column, row)| match self.face_at(column, row) {
+                Some((found, flipped)) if found == face => Some((column, row, flipped)),
+                _ => None
+            })
+            .unwrap_or((0, 0, false))
+    }
+
+    pub fn extract_faces<T: Float>(self, image: &Image<T>) -> Vec<(CubeFace, Image<T>)> {
+        let (columns, rows) = self.grid();
+        let face_size = (image.width() / columns).min(image.height() / rows);
+
+        CubeFace::ALL.iter()
+            .map(|face| {
+                let (column, row, flipped) = self.cell_of(*face);
+                let pixels = (0..face_size)
+                    .flat_map(|y| (0..face_size).map(move |x| (x, y)))
+                    .map(|(x, y)| {
+                        let (x, y) = if flipped { (face_size - 1 - x, face_size - 1 - y) } else { (x, y) };
+                        image.color_at(column * face_size + x, row * face_size + y).unwrap_or(Color::BLACK).cast()
+                    })
+                    .collect();
+
+                (*face, Image::from_pixels(face_size, face_size, pixels))
+            })
+            .collect()
+    }
+
+    pub fn write_faces<T: Float, P: AsRef<Path>>(self, image: &Image<T>, directory: P, prefix: &str) -> Result<()> {
+        for (face, face_image) in self.extract_faces(image) {
+            face_image.save_pbm(directory.as_ref().join(format!("{}_{}.pbm", prefix, face.name())))?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct CubeFaceLensShader {
+    face: CubeFace
+}
+
+impl CubeFaceLensShader {
+    pub fn new(face: CubeFace) -> CubeFaceLensShader {
+        CubeFaceLensShader { face }
+    }
+}
+
+impl LensShader for CubeFaceLensShader {
+    fn ray_to_lens_point(&self, x: f64, y: f64, _lens_x: f64, _lens_y: f64) -> Option<Ray> {
+        Some(Ray::new(Point::ZERO, self.face.direction(x, y)))
+    }
+}
+
+pub struct CubeMapLensShader {
+    layout: CubeMapLayout
+}
+
+impl CubeMapLensShader {
+    pub fn new(layout: CubeMapLayout) -> CubeMapLensShader {
+        CubeMapLensShader { layout }
+    }
+}
+
+impl LensShader for CubeMapLensShader {
+    fn ray_to_lens_point(&self, x: f64, y: f64, _lens_x: f64, _lens_y: f64) -> Option<Ray> {
+        let (columns, rows) = self.layout.grid();
+        let (cell_x, cell_y) = (x * columns as f64, y * rows as f64);
+        let (column, row) = (cell_x.floor().max(0.0) as usize, cell_y.floor().max(0.0) as usize);
+
+        let (face, flipped) = self.layout.face_at(column, row)?;
+        let (s, t) = (cell_x - column as f64, cell_y - row as f64);
+        let (s, t) = if flipped { (1.0 - s, 1.0 - t) } else { (s, t) };
+
+        Some(Ray::new(Point::ZERO, face.direction(s, t)))
+    }
+}
@@ -0,0 +1,67 @@
+use crate::math::vector::Vector;
+use crate::renderer::image::Image;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sensor {
+    pub width: f64,
+    pub height: f64
+}
+
+impl Sensor {
+    pub const FULL_FRAME: Sensor = Sensor { width: 36.0, height: 24.0 };
+    pub const APS_C: Sensor = Sensor { width: 23.6, height: 15.6 };
+    pub const MICRO_FOUR_THIRDS: Sensor = Sensor { width: 17.3, height: 13.0 };
+
+    pub fn new(width: f64, height: f64) -> Sensor {
+        Sensor { width, height }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FieldOfView {
+    Vertical(f64),
+    Horizontal(f64),
+    FocalLength { focal_length: f64, sensor: Sensor }
+}
+
+impl FieldOfView {
+    pub fn equivalent_35mm(focal_length: f64) -> FieldOfView {
+        FieldOfView::FocalLength { focal_length, sensor: Sensor::FULL_FRAME }
+    }
+
+    pub fn film_size(self, aspect_ratio: f64) -> Vector {
+        let (width, height) = match self {
+            FieldOfView::Vertical(radians) => {
+                let height = 2.0 * (radians / 2.0).tan();
+                (height * aspect_ratio, height)
+            }
+            FieldOfView::Horizontal(radians) => {
+                let width = 2.0 * (radians / 2.0).tan();
+                (width, width / aspect_ratio)
+            }
+            FieldOfView::FocalLength { focal_length, sensor } => {
+                let fitted = sensor.width.max(sensor.height) / focal_length;
+
+                if aspect_ratio >= 1.0 {
+                    (fitted, fitted / aspect_ratio)
+                } else {
+                    (fitted * aspect_ratio, fitted)
+                }
+            }
+        };
+
+        Vector::new(width, height, 0.0)
+    }
+
+    pub fn film_size_for_image(self, image: &Image) -> Vector {
+        self.film_size(image.width() as f64 / image.height().max(1) as f64)
+    }
+
+    pub fn vertical(self, aspect_ratio: f64) -> f64 {
+        2.0 * (self.film_size(aspect_ratio).y / 2.0).atan()
+    }
+
+    pub fn horizontal(self, aspect_ratio: f64) -> f64 {
+        2.0 * (self.film_size(aspect_ratio).x / 2.0).atan()
+    }
+}
@@ -0,0 +1,65 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+use crate::math::point::Point;
+use crate::math::ray::Ray;
+use crate::math::vector::Vector;
+use crate::renderer::lens_shader::LensShader;
+
+const MAXIMUM_STEREOGRAPHIC_ANGLE: f64 = PI * 0.999;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FisheyeProjection {
+    Equidistant,
+    Equisolid,
+    Orthographic,
+    Stereographic
+}
+
+pub struct FisheyeLensShader {
+    projection: FisheyeProjection,
+    half_angle: f64,
+    aspect_ratio: f64
+}
+
+impl FisheyeLensShader {
+    pub fn new(projection: FisheyeProjection, field_of_view: f64, aspect_ratio: f64) -> FisheyeLensShader {
+        let maximum_angle = match projection {
+            FisheyeProjection::Orthographic => FRAC_PI_2,
+            FisheyeProjection::Stereographic => MAXIMUM_STEREOGRAPHIC_ANGLE,
+            _ => PI
+        };
+
+        FisheyeLensShader {
+            projection,
+            half_angle: (field_of_view / 2.0).clamp(0.0, maximum_angle),
+            aspect_ratio
+        }
+    }
+
+    fn angle_from_radius(&self, radius: f64) -> f64 {
+        match self.projection {
+            FisheyeProjection::Equidistant => radius * self.half_angle,
+            FisheyeProjection::Equisolid => 2.0 * (radius * (self.half_angle / 2.0).sin()).clamp(-1.0, 1.0).asin(),
+            FisheyeProjection::Orthographic => (radius * self.half_angle.sin()).clamp(-1.0, 1.0).asin(),
+            FisheyeProjection::Stereographic => 2.0 * (radius * (self.half_angle / 2.0).tan()).atan()
+        }
+    }
+}
+
+impl LensShader for FisheyeLensShader {
+    fn ray_to_lens_point(&self, x: f64, y: f64, _lens_x: f64, _lens_y: f64) -> Option<Ray> {
+        let (px, py) = (2.0 * (x - 0.5) * self.aspect_ratio, 2.0 * (y - 0.5));
+        let radius = (px * px + py * py).sqrt();
+
+        if radius > 1.0 {
+            return None;
+        }
+
+        let (sine_theta, cosine_theta) = self.angle_from_radius(radius).sin_cos();
+        let (sine_phi, cosine_phi) = py.atan2(px).sin_cos();
+
+        Some(Ray::new(
+            Point::ZERO,
+            Vector::new(sine_theta * cosine_phi, sine_theta * sine_phi, cosine_theta)
+        ))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::math::ray::Ray;
+use crate::math::vector::Vector;
+use crate::renderer::lens_shader::LensShader;
+use crate::renderer::lens_shader::sphere_lens_shader::SphereLensShader;
+use crate::renderer::lens_shader::stereo_lens_shader::StereoMode;
+
+pub struct OmniDirectionalStereoLensShader {
+    sphere_lens_shader: SphereLensShader,
+    mode: StereoMode,
+    interocular_distance: f64
+}
+
+impl OmniDirectionalStereoLensShader {
+    pub fn new(mode: StereoMode, interocular_distance: f64) -> OmniDirectionalStereoLensShader {
+        OmniDirectionalStereoLensShader { sphere_lens_shader: SphereLensShader::full_sphere(1.0), mode, interocular_distance }
+    }
+}
+
+impl LensShader for OmniDirectionalStereoLensShader {
+    fn ray_to_lens_point(&self, x: f64, y: f64, lens_x: f64, lens_y: f64) -> Option<Ray> {
+        let (eye, x, y) = match self.mode {
+            StereoMode::Single(eye) => (eye, x, y),
+            StereoMode::Pair(layout) => layout.split(x, y)
+        };
+
+        let ray = self.sphere_lens_shader.ray_to_lens_point(x, y, lens_x, lens_y)?;
+        let horizontal = Vector::new(ray.direction.x, 0.0, ray.direction.z).normalized().unwrap_or(Vector::FORWARD);
+        let right = Vector::cross(Vector::UP, horizontal);
+
+        Some(Ray::timed(ray.origin + right * (eye.side() * self.interocular_distance / 2.0), ray.direction, ray.time))
+    }
+}
@@ -28,7 +28,7 @@ impl PanoramaOrthographicLensShader {
 }
 
 impl LensShader for PanoramaOrthographicLensShader{
-    fn ray_to_lens_point(&self, x: f64, y: f64) -> Option<Ray> {
+    fn ray_to_lens_point(&self, x: f64, y: f64, _lens_x: f64, _lens_y: f64) -> Option<Ray> {
         let ray = Ray::new(Point::ZERO, Vector::FORWARD * self.radius);
         let ray = Rotation::on_y(self.amplitude * (x - 0.5)) * ray;
         let ray = Translation::new(Vector::new(0.0, self.height * (y - 0.5), 0.0)) * ray;
@@ -27,7 +27,7 @@ impl PanoramaPerspectiveLensShader {
 }
 
 impl LensShader for PanoramaPerspectiveLensShader {
-    fn ray_to_lens_point(&self, x: f64, y: f64) -> Option<Ray> {
+    fn ray_to_lens_point(&self, x: f64, y: f64, _lens_x: f64, _lens_y: f64) -> Option<Ray> {
         let direction = Vector::FORWARD * self.radius;
         let direction = Rotation::on_y(self.amplitude * (x - 0.5)) * direction;
         let direction = direction + Vector::UP * self.height * (y - 0.5);
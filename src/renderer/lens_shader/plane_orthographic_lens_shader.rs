@@ -15,7 +15,7 @@ impl PlaneOrthographicalLensShader {
 }
 
 impl LensShader for PlaneOrthographicalLensShader {
-    fn ray_to_lens_point(&self, x: f64, y: f64) -> Option<Ray> {
+    fn ray_to_lens_point(&self, x: f64, y: f64, _lens_x: f64, _lens_y: f64) -> Option<Ray> {
         Some(Ray::new(
             self.top_left_point + Vector::component_wise_multiplication(self.size, Vector::new(x, y, 1.0)),
             Vector::FORWARD
@@ -1,4 +1,6 @@
 use crate::renderer::lens_shader::LensShader;
+use crate::renderer::lens_shader::field_of_view::FieldOfView;
+use crate::renderer::image::Image;
 use crate::math::point::Point;
 use crate::math::ray::Ray;
 use crate::math::vector::Vector;
@@ -12,10 +14,26 @@ impl PlanePerspectiveLensShader {
     pub fn new(focal_length: f64, size: Vector) -> PlanePerspectiveLensShader {
         PlanePerspectiveLensShader { size, top_left_point: Point::new(0.0, 0.0, focal_length) - size / 2.0 }
     }
+
+    pub fn from_field_of_view(field_of_view: FieldOfView, aspect_ratio: f64) -> PlanePerspectiveLensShader {
+        PlanePerspectiveLensShader::new(1.0, field_of_view.film_size(aspect_ratio))
+    }
+
+    pub fn from_vertical_fov(radians: f64, aspect_ratio: f64) -> PlanePerspectiveLensShader {
+        PlanePerspectiveLensShader::from_field_of_view(FieldOfView::Vertical(radians), aspect_ratio)
+    }
+
+    pub fn from_horizontal_fov(radians: f64, aspect_ratio: f64) -> PlanePerspectiveLensShader {
+        PlanePerspectiveLensShader::from_field_of_view(FieldOfView::Horizontal(radians), aspect_ratio)
+    }
+
+    pub fn for_image(field_of_view: FieldOfView, image: &Image) -> PlanePerspectiveLensShader {
+        PlanePerspectiveLensShader::new(1.0, field_of_view.film_size_for_image(image))
+    }
 }
 
 impl LensShader for PlanePerspectiveLensShader {
-    fn ray_to_lens_point(&self, x: f64, y: f64) -> Option<Ray> {
+    fn ray_to_lens_point(&self, x: f64, y: f64, _lens_x: f64, _lens_y: f64) -> Option<Ray> {
         Some(Ray::from_points(
             Point::ZERO,
             self.top_left_point + Vector::component_wise_multiplication(self.size, Vector::new(x, y, 1.0))
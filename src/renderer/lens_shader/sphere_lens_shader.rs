@@ -22,7 +22,7 @@ impl SphereLensShader {
 }
 
 impl LensShader for SphereLensShader {
-    fn ray_to_lens_point(&self, x: f64, y: f64) -> Option<Ray> {
+    fn ray_to_lens_point(&self, x: f64, y: f64, _lens_x: f64, _lens_y: f64) -> Option<Ray> {
         let direction = Vector::FORWARD * self.radius;
         let direction = Rotation::on_y(self.amplitude_x * (x - 0.5)) * direction;
         let direction = Rotation::on_x(self.amplitude_y * (y - 0.5)) * direction;
@@ -0,0 +1,90 @@
+use crate::math::point::Point;
+use crate::math::ray::Ray;
+use crate::math::vector::Vector;
+use crate::renderer::lens_shader::LensShader;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Eye {
+    Left,
+    Right
+}
+
+impl Eye {
+    pub fn side(self) -> f64 {
+        match self {
+            Eye::Left => -1.0,
+            Eye::Right => 1.0
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum StereoLayout {
+    SideBySide,
+    TopBottom
+}
+
+impl StereoLayout {
+    pub fn split(self, x: f64, y: f64) -> (Eye, f64, f64) {
+        match self {
+            StereoLayout::SideBySide if x < 0.5 => (Eye::Left, 2.0 * x, y),
+            StereoLayout::SideBySide => (Eye::Right, 2.0 * x - 1.0, y),
+            StereoLayout::TopBottom if y < 0.5 => (Eye::Left, x, 2.0 * y),
+            StereoLayout::TopBottom => (Eye::Right, x, 2.0 * y - 1.0)
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum StereoMode {
+    Single(Eye),
+    Pair(StereoLayout)
+}
+
+pub struct StereoLensShader<L: LensShader> {
+    lens_shader: L,
+    mode: StereoMode,
+    interocular_distance: f64,
+    convergence_distance: f64
+}
+
+impl<L: LensShader> StereoLensShader<L> {
+    pub fn new(lens_shader: L, mode: StereoMode, interocular_distance: f64, convergence_distance: f64) -> StereoLensShader<L> {
+        StereoLensShader { lens_shader, mode, interocular_distance, convergence_distance }
+    }
+
+    pub fn eye(lens_shader: L, eye: Eye, interocular_distance: f64, convergence_distance: f64) -> StereoLensShader<L> {
+        StereoLensShader::new(lens_shader, StereoMode::Single(eye), interocular_distance, convergence_distance)
+    }
+
+    pub fn pair(lens_shader: L, layout: StereoLayout, interocular_distance: f64, convergence_distance: f64) -> StereoLensShader<L> {
+        StereoLensShader::new(lens_shader, StereoMode::Pair(layout), interocular_distance, convergence_distance)
+    }
+
+    fn eye_ray(&self, eye: Eye, ray: Ray) -> Ray {
+        let offset = Vector::RIGHT * (eye.side() * self.interocular_distance / 2.0);
+
+        if self.convergence_distance <= 0.0 || ray.direction.z <= 0.0 {
+            return Ray::timed(ray.origin + offset, ray.direction, ray.time);
+        }
+
+        let convergence_point = ray.origin + ray.direction * ((self.convergence_distance - (ray.origin - Point::ZERO).z) / ray.direction.z);
+        Ray::from_points(ray.origin + offset, convergence_point).with_time(ray.time)
+    }
+}
+
+impl<L: LensShader> LensShader for StereoLensShader<L> {
+    fn ray_to_lens_point(&self, x: f64, y: f64, lens_x: f64, lens_y: f64) -> Option<Ray> {
+        self.ray_to_lens_point_at(x, y, lens_x, lens_y, 0.0)
+    }
+
+    fn ray_to_lens_point_at(&self, x: f64, y: f64, lens_x: f64, lens_y: f64, time: f64) -> Option<Ray> {
+        let (eye, x, y) = match self.mode {
+            StereoMode::Single(eye) => (eye, x, y),
+            StereoMode::Pair(layout) => layout.split(x, y)
+        };
+
+        let ray = self.lens_shader.ray_to_lens_point_at(x, y, lens_x, lens_y, time)?;
+        Some(self.eye_ray(eye, ray))
+    }
+}
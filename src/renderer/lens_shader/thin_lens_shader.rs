@@ -0,0 +1,48 @@
+use crate::math::point::Point;
+use crate::math::ray::Ray;
+use crate::math::vector::Vector;
+use crate::renderer::image::Image;
+use crate::renderer::lens_shader::aperture::Aperture;
+use crate::renderer::lens_shader::field_of_view::FieldOfView;
+use crate::renderer::lens_shader::LensShader;
+
+pub struct ThinLensShader {
+    pub top_left_point: Point,
+    pub size: Vector,
+    pub aperture_radius: f64,
+    pub focus_distance: f64,
+    pub aperture: Aperture
+}
+
+impl ThinLensShader {
+    pub fn new(focal_length: f64, size: Vector, aperture_radius: f64, focus_distance: f64, aperture: Aperture) -> ThinLensShader {
+        ThinLensShader {
+            top_left_point: Point::new(0.0, 0.0, focal_length) - size / 2.0,
+            size,
+            aperture_radius,
+            focus_distance,
+            aperture
+        }
+    }
+
+    pub fn for_image(field_of_view: FieldOfView, image: &Image, aperture_radius: f64, focus_distance: f64, aperture: Aperture) -> ThinLensShader {
+        ThinLensShader::new(1.0, field_of_view.film_size_for_image(image), aperture_radius, focus_distance, aperture)
+    }
+}
+
+impl LensShader for ThinLensShader {
+    fn ray_to_lens_point(&self, x: f64, y: f64, lens_x: f64, lens_y: f64) -> Option<Ray> {
+        let direction = self.top_left_point + Vector::component_wise_multiplication(self.size, Vector::new(x, y, 1.0)) - Point::ZERO;
+
+        if direction.z <= 0.0 {
+            return None;
+        }
+
+        let focus_point = Point::ZERO + direction * (self.focus_distance / direction.z);
+
+        let (aperture_x, aperture_y) = self.aperture.sample(lens_x, lens_y);
+        let lens_point = Point::new(aperture_x * self.aperture_radius, aperture_y * self.aperture_radius, 0.0);
+
+        Some(Ray::from_points(lens_point, focus_point))
+    }
+}
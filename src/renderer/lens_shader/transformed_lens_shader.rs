@@ -0,0 +1,48 @@
+use crate::math::matrix::Matrix;
+use crate::math::point::Point;
+use crate::math::ray::Ray;
+use crate::math::transformation::animated_transform::AnimatedTransform;
+use crate::math::transformation::transform::Transform;
+use crate::math::transformation::transformation::Transformation;
+use crate::math::vector::Vector;
+use crate::renderer::lens_shader::LensShader;
+
+pub struct TransformedLensShader<L: LensShader> {
+    transform_matrix: Matrix,
+    animation: Option<AnimatedTransform>,
+    lens_shader: L
+}
+
+impl<L: LensShader> TransformedLensShader<L> {
+    pub fn new(transform: Transform, lens_shader: L) -> TransformedLensShader<L> {
+        TransformedLensShader { transform_matrix: transform.matrix(), animation: None, lens_shader }
+    }
+
+    pub fn from_matrix(transform_matrix: Matrix, lens_shader: L) -> TransformedLensShader<L> {
+        TransformedLensShader { transform_matrix, animation: None, lens_shader }
+    }
+
+    pub fn animated(animation: AnimatedTransform, lens_shader: L) -> TransformedLensShader<L> {
+        TransformedLensShader { transform_matrix: animation.at(0.0).matrix(), animation: Some(animation), lens_shader }
+    }
+
+    pub fn look_at(eye: Point, target: Point, up: Vector, lens_shader: L) -> Option<TransformedLensShader<L>> {
+        let transform_matrix = Transform::look_at(eye, target, up)?.matrix();
+        Some(TransformedLensShader { transform_matrix, animation: None, lens_shader })
+    }
+}
+
+impl<L: LensShader> LensShader for TransformedLensShader<L> {
+    fn ray_to_lens_point(&self, x: f64, y: f64, lens_x: f64, lens_y: f64) -> Option<Ray> {
+        Some(self.transform_matrix * self.lens_shader.ray_to_lens_point(x, y, lens_x, lens_y)?)
+    }
+
+    fn ray_to_lens_point_at(&self, x: f64, y: f64, lens_x: f64, lens_y: f64, time: f64) -> Option<Ray> {
+        let transform_matrix = match &self.animation {
+            Some(animation) => animation.at(time).matrix(),
+            _ => self.transform_matrix
+        };
+
+        Some(transform_matrix * self.lens_shader.ray_to_lens_point_at(x, y, lens_x, lens_y, time)?)
+    }
+}
@@ -0,0 +1,96 @@
+use crate::math::color::Color;
+use crate::math::matrix::Matrix;
+use crate::math::point::Point;
+use crate::math::vector::Vector;
+
+#[derive(Copy, Clone)]
+pub enum Light {
+    Directional { direction: Vector, color: Color },
+    Point { position: Point, color: Color, range: Option<f64> },
+    Spot { position: Point, direction: Vector, color: Color, range: Option<f64>, inner_cone: f64, outer_cone: f64 }
+}
+
+#[derive(Copy, Clone)]
+pub struct LightSample {
+    pub direction: Vector,
+    pub distance: f64,
+    pub color: Color
+}
+
+impl Light {
+    pub fn directional(direction: Vector, color: Color) -> Light {
+        Light::Directional { direction: direction.normalized_or_zero(), color }
+    }
+
+    pub fn point(position: Point, color: Color) -> Light {
+        Light::Point { position, color, range: None }
+    }
+
+    pub fn spot(position: Point, direction: Vector, color: Color, inner_cone: f64, outer_cone: f64) -> Light {
+        Light::Spot { position, direction: direction.normalized_or_zero(), color, range: None, inner_cone, outer_cone }
+    }
+
+    pub fn scaled(self, factor: f64) -> Light {
+        match self {
+            Light::Directional { direction, color } => Light::Directional { direction, color: color * factor },
+            Light::Point { position, color, range } => Light::Point { position, color: color * factor, range },
+            Light::Spot { position, direction, color, range, inner_cone, outer_cone } => {
+                Light::Spot { position, direction, color: color * factor, range, inner_cone, outer_cone }
+            }
+        }
+    }
+
+    pub fn transformed(self, matrix: Matrix) -> Light {
+        match self {
+            Light::Directional { direction, color } => Light::directional(matrix * direction, color),
+            Light::Point { position, color, range } => Light::Point { position: matrix * position, color, range },
+            Light::Spot { position, direction, color, range, inner_cone, outer_cone } => Light::Spot {
+                position: matrix * position,
+                direction: (matrix * direction).normalized_or_zero(),
+                color,
+                range,
+                inner_cone,
+                outer_cone
+            }
+        }
+    }
+
+    pub fn sample(&self, point: Point) -> Option<LightSample> {
+        match *self {
+            Light::Directional { direction, color } => Some(LightSample { direction: -direction, distance: f64::INFINITY, color }),
+            Light::Point { position, color, range } => Light::attenuated(point, position, color, range),
+            Light::Spot { position, direction, color, range, inner_cone, outer_cone } => {
+                let sample = Light::attenuated(point, position, color, range)?;
+                let cosine = Vector::dot(-sample.direction, direction);
+                let (cosine_inner, cosine_outer) = (inner_cone.cos(), outer_cone.cos());
+
+                let falloff = if cosine_inner - cosine_outer > f64::EPSILON {
+                    ((cosine - cosine_outer) / (cosine_inner - cosine_outer)).clamp(0.0, 1.0)
+                } else if cosine >= cosine_outer { 1.0 } else { 0.0 };
+
+                if falloff <= 0.0 {
+                    return None;
+                }
+
+                Some(LightSample { color: sample.color * (falloff * falloff), ..sample })
+            }
+        }
+    }
+
+    fn attenuated(point: Point, position: Point, color: Color, range: Option<f64>) -> Option<LightSample> {
+        let offset = position - point;
+        let distance = offset.length();
+        let direction = offset.normalized()?;
+
+        let window = match range {
+            Some(range) => (1.0 - (distance / range).powi(4)).clamp(0.0, 1.0),
+            _ => 1.0
+        };
+
+        if window <= 0.0 {
+            return None;
+        }
+
+        Some(LightSample { direction, distance, color: color * (window / (distance * distance)) })
+    }
+}
@@ -3,3 +3,8 @@ pub mod lens_shader;
 pub mod ray_shader;
 pub mod renderer;
 pub mod pixel_shader;
+pub mod environment;
+
+pub mod timeline;
+pub mod sequence_renderer;
+pub mod light;
@@ -1,9 +1,17 @@
 use std::collections::VecDeque;
 use crate::math::color::Color;
+use crate::math::ray::Ray;
 use crate::surfaces::surface::{Surface, SurfacePoint};
 
 pub mod default_pixel_shader;
+pub mod environment_light_pixel_shader;
 
 pub trait PixelShader {
-    fn final_color<S: Surface>(&self, stack: &VecDeque<SurfacePoint>, surface: &S) -> Color;
+    fn final_color<S: Surface>(&self, stack: &VecDeque<SurfacePoint>, escaped_ray: Option<Ray>, surface: &S) -> Color;
+}
+
+impl<F: PixelShader> PixelShader for &F {
+    fn final_color<S: Surface>(&self, stack: &VecDeque<SurfacePoint>, escaped_ray: Option<Ray>, surface: &S) -> Color {
+        (**self).final_color(stack, escaped_ray, surface)
+    }
 }
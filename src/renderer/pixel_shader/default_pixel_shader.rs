@@ -1,17 +1,34 @@
 use std::collections::VecDeque;
 use crate::renderer::pixel_shader::PixelShader;
 use crate::math::color::Color;
+use crate::math::ray::Ray;
+use crate::renderer::environment::Environment;
 use crate::surfaces::surface::{Surface, SurfacePoint};
 
-pub struct DefaultPixelShader;
+pub struct DefaultPixelShader<E: Environment> {
+    environment: E
+}
 
-impl PixelShader for DefaultPixelShader {
-    fn final_color<S: Surface>(&self, stack: &VecDeque<SurfacePoint>, surface: &S) -> Color {
-        if stack.is_empty() {
-            return Color::BLACK;
-        }
+impl<E: Environment> DefaultPixelShader<E> {
+    pub fn new(environment: E) -> DefaultPixelShader<E> {
+        DefaultPixelShader { environment }
+    }
+
+    pub fn environment(&self) -> &E {
+        &self.environment
+    }
+}
+
+impl<E: Environment> PixelShader for DefaultPixelShader<E> {
+    fn final_color<S: Surface>(&self, stack: &VecDeque<SurfacePoint>, escaped_ray: Option<Ray>, _surface: &S) -> Color {
+        let background = match escaped_ray {
+            Some(ray) => self.environment.color(ray.direction),
+            _ => Color::WHITE
+        };
 
         stack.iter()
-            .rfold(Color::WHITE, |accumulator, surface_point| surface_point.material.diffuse * accumulator)
+            .rfold(background, |accumulator, surface_point| {
+                surface_point.material.emission_at(surface_point) + surface_point.material.diffuse_at(surface_point) * accumulator
+            })
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,123 @@
+use std::collections::VecDeque;
+use std::f64::consts::PI;
+use crate::math::color::Color;
+use crate::math::normal::Normal;
+use crate::math::ray::Ray;
+use crate::math::sampling;
+use crate::renderer::environment::{DirectionalLight, Environment};
+use crate::renderer::light::Light;
+use crate::renderer::pixel_shader::PixelShader;
+use crate::surfaces::surface::{Surface, SurfacePoint};
+
+pub struct EnvironmentLightPixelShader<E: Environment> {
+    environment: E,
+    light_samples: usize,
+    lights: Vec<Light>
+}
+
+impl<E: Environment> EnvironmentLightPixelShader<E> {
+    pub fn new(environment: E, light_samples: usize) -> EnvironmentLightPixelShader<E> {
+        EnvironmentLightPixelShader { environment, light_samples: light_samples.max(1), lights: Vec::new() }
+    }
+
+    pub fn with_lights(self, lights: Vec<Light>) -> EnvironmentLightPixelShader<E> {
+        EnvironmentLightPixelShader { lights, ..self }
+    }
+
+    pub fn environment(&self) -> &E {
+        &self.environment
+    }
+
+    pub fn light_samples(&self) -> usize {
+        self.light_samples
+    }
+
+    pub fn lights(&self) -> &[Light] {
+        &self.lights
+    }
+
+    fn irradiance<S: Surface>(&self, surface_point: &SurfacePoint, surface: &S) -> Color {
+        let (normal, shading_normal) = (surface_point.normal, surface_point.shading_normal);
+        let seed = sampling::point_seed(surface_point.point);
+        let offset = (sampling::hash_to_unit(seed), sampling::hash_to_unit(seed ^ 1));
+
+        let irradiance = (0..self.light_samples)
+            .map(|index| sampling::rotated(sampling::hammersley(index, self.light_samples), offset))
+            .map(|(u, v)| self.environment.sample(u, v))
+            .filter(|sample| sample.pdf > 0.0)
+            .fold(Color::ZERO, |irradiance, sample| {
+                let direction = sample.direction.normalized_or_zero();
+                let cosine = Normal::dot(shading_normal, direction);
+                if cosine <= 0.0 || Normal::dot(normal, direction) <= 0.0 {
+                    return irradiance;
+                }
+
+                let shadow_ray = surface_point.spawn_ray(sample.direction);
+                if surface.intersect(shadow_ray).is_some() {
+                    return irradiance;
+                }
+
+                irradiance + sample.color * (cosine / sample.pdf)
+            });
+
+        let irradiance = irradiance / self.light_samples as f64
+            + self.lights.iter().fold(Color::ZERO, |irradiance, light| irradiance + self.light_irradiance(light, normal, shading_normal, surface_point, surface));
+
+        match self.environment.directional_light() {
+            Some(light) => irradiance + self.directional_irradiance(light, normal, shading_normal, surface_point, surface),
+            _ => irradiance
+        }
+    }
+
+    fn directional_irradiance<S: Surface>(&self, light: DirectionalLight, normal: Normal, shading_normal: Normal, surface_point: &SurfacePoint, surface: &S) -> Color {
+        let cosine = Normal::dot(shading_normal, light.direction);
+        if cosine <= 0.0 || Normal::dot(normal, light.direction) <= 0.0 {
+            return Color::ZERO;
+        }
+
+        let shadow_ray = surface_point.spawn_ray(light.direction);
+        if surface.intersect(shadow_ray).is_some() {
+            return Color::ZERO;
+        }
+
+        light.color * cosine
+    }
+
+    fn light_irradiance<S: Surface>(&self, light: &Light, normal: Normal, shading_normal: Normal, surface_point: &SurfacePoint, surface: &S) -> Color {
+        let sample = match light.sample(surface_point.point) {
+            Some(sample) => sample,
+            _ => return Color::ZERO
+        };
+
+        let cosine = Normal::dot(shading_normal, sample.direction);
+        if cosine <= 0.0 || Normal::dot(normal, sample.direction) <= 0.0 {
+            return Color::ZERO;
+        }
+
+        let shadow_ray = surface_point.spawn_ray(sample.direction).with_interval(0.0, sample.distance);
+        if surface.intersect(shadow_ray).is_some() {
+            return Color::ZERO;
+        }
+
+        sample.color * cosine
+    }
+}
+
+impl<E: Environment> PixelShader for EnvironmentLightPixelShader<E> {
+    fn final_color<S: Surface>(&self, stack: &VecDeque<SurfacePoint>, escaped_ray: Option<Ray>, surface: &S) -> Color {
+        let background = match escaped_ray {
+            Some(ray) => self.environment.color(ray.direction),
+            _ => Color::BLACK
+        };
+
+        let color = stack.iter()
+            .rfold(background, |accumulator, surface_point| {
+                let material = &surface_point.material;
+                let direct = self.irradiance(surface_point, surface) / PI;
+
+                material.emission_at(surface_point) + material.diffuse_at(surface_point) * (direct + accumulator * material.reflectivity_at(surface_point))
+            });
+
+        Color::with_alpha(color, 1.0)
+    }
+}
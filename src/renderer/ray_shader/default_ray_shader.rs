@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 use crate::renderer::ray_shader::RayShader;
+use crate::math::normal::Normal;
 use crate::math::ray::Ray;
 use crate::math::vector::Vector;
 use crate::surfaces::surface::SurfacePoint;
@@ -20,13 +21,15 @@ impl RayShader for DefaultRayShader {
             return None;
         }
 
-        Some(Ray::new(
-            surface_point.point,
-            Vector::reflection(ray.direction, surface_point.normal)
-        ))
+        let reflected = Vector::reflection(ray.direction, surface_point.shading_normal.vector());
+
+        let same_side = Normal::dot(surface_point.normal, reflected) * Normal::dot(surface_point.normal, ray.direction) < 0.0;
+        let reflected = if same_side { reflected } else { Vector::reflection(ray.direction, surface_point.normal.vector()) };
+
+        Some(surface_point.spawn_ray(reflected))
     }
 
-    fn on_intersection(&self, ray: Ray, surface_point: SurfacePoint, reflection_stack: &mut VecDeque<SurfacePoint>) {
+    fn on_intersection(&self, _ray: Ray, surface_point: SurfacePoint, reflection_stack: &mut VecDeque<SurfacePoint>) {
         reflection_stack.push_back(surface_point);
     }
 
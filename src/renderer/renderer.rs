@@ -1,8 +1,10 @@
 use std::collections::VecDeque;
-use std::sync::{Arc, Mutex};
+use std::ops::Range;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use scoped_pool::Pool;
 use crate::math::color::Color;
+use crate::math::float::Float;
+use crate::math::sampling;
 use crate::renderer::pixel_shader::PixelShader;
 use crate::renderer::image::Image;
 use crate::renderer::lens_shader::LensShader;
@@ -16,71 +18,136 @@ pub struct Renderer<L: LensShader + Send + Sync, R: RayShader + Send + Sync, F:
     lens_shader: L,
     reflection_shader: R,
     fragment_shader: F,
+    samples_per_pixel: usize,
+    fill_color: Color,
+    shutter: (f64, f64),
     thread_pool: Pool
 }
 
 impl<L: LensShader + Send + Sync, R: RayShader + Send + Sync, F: PixelShader + Send + Sync> Renderer<L, R, F> {
     pub fn new(lens_shader: L, reflection_shader: R, fragment_shader: F) -> Renderer<L, R, F> {
-        Renderer { lens_shader, reflection_shader, fragment_shader, thread_pool: Pool::new(WORKER_COUNT) }
+        Renderer { lens_shader, reflection_shader, fragment_shader, samples_per_pixel: 1, fill_color: Color::BLACK, shutter: (0.0, 0.0), thread_pool: Pool::new(WORKER_COUNT) }
     }
 
-    pub fn render<S: Surface + Send + Sync>(&self, image: &mut Image, surface: &S) {
+    pub fn set_samples_per_pixel(&mut self, samples_per_pixel: usize) {
+        self.samples_per_pixel = samples_per_pixel.max(1);
+    }
+
+    pub fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    pub fn set_fill_color(&mut self, fill_color: Color) {
+        self.fill_color = fill_color;
+    }
+
+    pub fn set_shutter(&mut self, open: f64, close: f64) {
+        self.shutter = (open, close.max(open));
+    }
+
+    pub fn shutter(&self) -> (f64, f64) {
+        self.shutter
+    }
+
+    pub fn set_thread_count(&mut self, thread_count: usize) {
+        self.thread_pool.shutdown();
+        self.thread_pool = Pool::new(thread_count.max(1));
+    }
+
+    pub fn render<T: Float, S: Surface + Send + Sync>(&self, image: &mut Image<T>, surface: &S) {
+        let resolution = image.resolution();
+        self.render_region(image, resolution, (0, 0), surface);
+    }
+
+    pub fn render_region<T: Float, S: Surface + Send + Sync>(&self, image: &mut Image<T>, resolution: (usize, usize), offset: (usize, usize), surface: &S) {
         let (tx, rx) = channel::<(usize, usize, Color)>();
-        let (width, height) = image.resolution();
+        let (region_width, region_height) = image.resolution();
 
         self.thread_pool.scoped(|scope| {
-            scope.execute(move || Self::wait_for_transmitted_pixels(image, rx));
+            for py in offset.1..offset.1 + region_height {
+                let tx = tx.clone();
+                scope.execute(move || self.render_line(py, offset.0..offset.0 + region_width, resolution, surface, tx))
+            }
 
-            scope.zoom(|scope| {
-                for py in 0..height {
-                    let tx = tx.clone();
-                    scope.execute(move || Self::render_line(py, width, height, &self.lens_shader, &self.reflection_shader, &self.fragment_shader, surface, tx))
-                }
-
-                drop(tx);
-            });
+            drop(tx);
+            Self::wait_for_transmitted_pixels(image, offset, rx);
         });
     }
 
-    fn render_line<S: Surface + Send + Sync>(py: usize, width: usize, height: usize, lens_shader: &L, reflection_shader: &R, fragment_shader: &F, surface: &S, transmitter: Sender<(usize, usize, Color)>) {
-        for px in 0..width {
-            Renderer::render_pixel(px, py, width, height, lens_shader, reflection_shader, fragment_shader, surface, transmitter.clone());
+    fn render_line<S: Surface + Send + Sync>(&self, py: usize, columns: Range<usize>, resolution: (usize, usize), surface: &S, transmitter: Sender<(usize, usize, Color)>) {
+        let rays = columns.clone()
+            .flat_map(|px| (0..self.samples_per_pixel).map(move |index| self.sample_ray(px, py, index, resolution)))
+            .collect::<Vec<Option<Ray>>>();
+
+        let primary_rays = rays.iter().flatten().copied().collect::<Vec<Ray>>();
+        let mut primary_hits = surface.intersect_rays(&primary_rays).into_iter();
+
+        let colors = rays.iter()
+            .map(|ray| match ray {
+                Some(ray) => self.render_sample(*ray, primary_hits.next().flatten(), surface),
+                _ => self.fill_color
+            })
+            .collect::<Vec<Color>>();
+
+        for (px, samples) in columns.zip(colors.chunks(self.samples_per_pixel)) {
+            let color_sum = samples.iter().fold(Color::ZERO, |sum, &color| sum + color);
+            transmitter.send((px, py, color_sum / self.samples_per_pixel as f64)).unwrap();
         }
     }
 
-    fn render_pixel<S: Surface + Send + Sync>(px: usize, py: usize, width: usize, height: usize, lens_shader: &L, reflection_shader: &R, fragment_shader: &F, surface: &S, transmitter: Sender<(usize, usize, Color)>) {
-        let (nx, ny) = ((px as f64 + 0.5) / width as f64, (py as f64 + 0.5) / height as f64);
+    fn sample_ray(&self, px: usize, py: usize, index: usize, (width, height): (usize, usize)) -> Option<Ray> {
+        let seed = sampling::hash((py * width + px) as u64);
+        let pixel_offset = (sampling::hash_to_unit(seed), sampling::hash_to_unit(seed ^ 1));
+        let lens_offset = (sampling::hash_to_unit(seed ^ 2), sampling::hash_to_unit(seed ^ 3));
+        let time_offset = sampling::hash_to_unit(seed ^ 4);
 
-        let ray = lens_shader.ray_to_lens_point(nx, ny).unwrap();
-        let reflections = Self::propagate_ray(reflection_shader, ray, surface);
-        let final_color = fragment_shader.final_color(&reflections, surface);
+        let (pixel_sample, lens_sample, time_sample) = if self.samples_per_pixel == 1 {
+            ((0.5, 0.5), (0.5, 0.5), 0.0)
+        } else {
+            let sample = sampling::hammersley(index, self.samples_per_pixel);
+            let time_sample = (sampling::radical_inverse_base(index as u64, 3) + time_offset).fract();
+            (sampling::rotated(sample, pixel_offset), sampling::rotated((sample.1, sample.0), lens_offset), time_sample)
+        };
+
+        let (nx, ny) = ((px as f64 + pixel_sample.0) / width as f64, (py as f64 + pixel_sample.1) / height as f64);
+        let time = self.shutter.0 + (self.shutter.1 - self.shutter.0) * time_sample;
+
+        self.lens_shader.ray_to_lens_point_at(nx, ny, lens_sample.0, lens_sample.1, time)
+    }
 
-        transmitter.send((px, py, final_color)).unwrap();
+    fn render_sample<S: Surface + Send + Sync>(&self, ray: Ray, primary_hit: Option<SurfacePoint>, surface: &S) -> Color {
+        let (reflections, escaped_ray) = Self::propagate_ray(&self.reflection_shader, ray, primary_hit, surface);
+        self.fragment_shader.final_color(&reflections, escaped_ray, surface)
     }
 
-    fn wait_for_transmitted_pixels(image: &mut Image, receiver: Receiver<(usize, usize, Color)>) {
+    fn wait_for_transmitted_pixels<T: Float>(image: &mut Image<T>, offset: (usize, usize), receiver: Receiver<(usize, usize, Color)>) {
         for (px, py, color) in receiver {
-            image.paint(px, py, color);
+            image.paint(px - offset.0, py - offset.1, color);
         }
     }
 
-    fn propagate_ray<S: Surface>(reflection_shader: &R, mut ray: Ray, surface: &S) -> VecDeque<SurfacePoint> {
+    fn propagate_ray<S: Surface>(reflection_shader: &R, mut ray: Ray, primary_hit: Option<SurfacePoint>, surface: &S) -> (VecDeque<SurfacePoint>, Option<Ray>) {
         let mut reflection_stack: VecDeque<SurfacePoint> = VecDeque::with_capacity(reflection_shader.reflection_count_hint());
+        let mut hit = primary_hit;
 
-        loop {
-            let reflection_point = match surface.intersect(ray) {
-                Some(point) => point,
-                _ => break
-            };
-
-            reflection_shader.on_intersection(ray, reflection_point, &mut reflection_stack);
+        while let Some(reflection_point) = hit {
+            let reflection_point = reflection_point.shaded();
+            reflection_shader.on_intersection(ray, reflection_point.clone(), &mut reflection_stack);
 
             ray = match reflection_shader.next_ray(ray, reflection_point, &reflection_stack) {
                 Some(ray) => ray,
-                _ => break
+                _ => return (reflection_stack, None)
             };
+
+            hit = surface.intersect(ray);
         }
 
-        reflection_stack
+        (reflection_stack, Some(ray))
     }
-}
\ No newline at end of file
+}
+
+impl<L: LensShader + Send + Sync, R: RayShader + Send + Sync, F: PixelShader + Send + Sync> Drop for Renderer<L, R, F> {
+    fn drop(&mut self) {
+        self.thread_pool.shutdown();
+    }
+}
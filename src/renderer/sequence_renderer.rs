@@ -0,0 +1,58 @@
+use std::fs;
+use std::io::Result;
+use std::path::{Path, PathBuf};
+use crate::math::float::Float;
+use crate::renderer::image::Image;
+use crate::renderer::lens_shader::LensShader;
+use crate::renderer::pixel_shader::PixelShader;
+use crate::renderer::ray_shader::RayShader;
+use crate::renderer::renderer::Renderer;
+use crate::renderer::timeline::Timeline;
+use crate::surfaces::surface::Surface;
+
+pub struct SequenceRenderer<L: LensShader + Send + Sync, R: RayShader + Send + Sync, F: PixelShader + Send + Sync> {
+    renderer: Renderer<L, R, F>,
+    timeline: Timeline
+}
+
+impl<L: LensShader + Send + Sync, R: RayShader + Send + Sync, F: PixelShader + Send + Sync> SequenceRenderer<L, R, F> {
+    pub fn new(renderer: Renderer<L, R, F>, timeline: Timeline) -> SequenceRenderer<L, R, F> {
+        SequenceRenderer { renderer, timeline }
+    }
+
+    pub fn renderer(&mut self) -> &mut Renderer<L, R, F> {
+        &mut self.renderer
+    }
+
+    pub fn timeline(&self) -> Timeline {
+        self.timeline
+    }
+
+    pub fn frame_path<P: AsRef<Path>>(directory: P, frame: usize) -> PathBuf {
+        directory.as_ref().join(format!("frame_{:04}.png", frame))
+    }
+
+    pub fn render_frame<T: Float, S: Surface + Send + Sync>(&mut self, frame: usize, image: &mut Image<T>, surface: &S) {
+        let (open, close) = self.timeline.shutter_at(frame);
+
+        self.renderer.set_shutter(open, close);
+        self.renderer.render(image, surface);
+    }
+
+    pub fn render<S: Surface + Send + Sync, P: AsRef<Path>>(&mut self, width: usize, height: usize, surface: &S, directory: P) -> Result<Vec<PathBuf>> {
+        fs::create_dir_all(directory.as_ref())?;
+
+        let mut paths = Vec::with_capacity(self.timeline.frame_count());
+
+        for frame in self.timeline.frames() {
+            let mut image: Image = Image::new(width, height);
+            self.render_frame(frame, &mut image, surface);
+
+            let path = Self::frame_path(directory.as_ref(), frame);
+            image.save_png(&path)?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+}
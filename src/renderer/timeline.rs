@@ -0,0 +1,40 @@
+use std::ops::RangeInclusive;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Timeline {
+    pub start_frame: usize,
+    pub end_frame: usize,
+    pub fps: f64,
+    pub shutter_angle: f64
+}
+
+impl Timeline {
+    pub fn new(start_frame: usize, end_frame: usize, fps: f64) -> Timeline {
+        Timeline { start_frame, end_frame: end_frame.max(start_frame), fps: fps.max(f64::EPSILON), shutter_angle: 180.0 }
+    }
+
+    pub fn with_shutter_angle(self, shutter_angle: f64) -> Timeline {
+        Timeline { shutter_angle: shutter_angle.clamp(0.0, 360.0), ..self }
+    }
+
+    pub fn frames(&self) -> RangeInclusive<usize> {
+        self.start_frame..=self.end_frame
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.end_frame - self.start_frame + 1
+    }
+
+    pub fn frame_duration(&self) -> f64 {
+        1.0 / self.fps
+    }
+
+    pub fn time_at(&self, frame: usize) -> f64 {
+        frame as f64 / self.fps
+    }
+
+    pub fn shutter_at(&self, frame: usize) -> (f64, f64) {
+        let open = self.time_at(frame);
+        (open, open + self.frame_duration() * self.shutter_angle / 360.0)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::math::matrix::Matrix;
+use crate::math::point::Point;
+use crate::math::transformation::transform::Transform;
+use crate::math::transformation::transformation::Transformation;
+use crate::math::vector::Vector;
+use crate::renderer::lens_shader::aperture::Aperture;
+use crate::renderer::lens_shader::cube_map_lens_shader::{CubeMapLayout, CubeMapLensShader};
+use crate::renderer::lens_shader::field_of_view::FieldOfView;
+use crate::renderer::lens_shader::fisheye_lens_shader::{FisheyeLensShader, FisheyeProjection};
+use crate::renderer::lens_shader::LensShader;
+use crate::renderer::lens_shader::panorama_perspective_lens_shader::PanoramaPerspectiveLensShader;
+use crate::renderer::lens_shader::plane_orthographic_lens_shader::PlaneOrthographicalLensShader;
+use crate::renderer::lens_shader::plane_perspective_lens_shader::PlanePerspectiveLensShader;
+use crate::renderer::lens_shader::sphere_lens_shader::SphereLensShader;
+use crate::renderer::lens_shader::thin_lens_shader::ThinLensShader;
+use crate::renderer::lens_shader::transformed_lens_shader::TransformedLensShader;
+
+#[derive(Clone)]
+pub enum CameraLens {
+    Perspective(FieldOfView),
+    Orthographic { height: f64 },
+    ThinLens { field_of_view: FieldOfView, aperture_radius: f64, focus_distance: f64, aperture: Aperture },
+    Fisheye { projection: FisheyeProjection, field_of_view: f64 },
+    Sphere,
+    Panorama { height: f64, radius: f64 },
+    CubeMap(CubeMapLayout)
+}
+
+impl CameraLens {
+    pub fn default_field_of_view() -> FieldOfView {
+        FieldOfView::Vertical(60f64.to_radians())
+    }
+
+    pub fn named(kind: &str) -> Option<CameraLens> {
+        let lens = match kind {
+            "perspective" => CameraLens::Perspective(CameraLens::default_field_of_view()),
+            "thin_lens" => CameraLens::ThinLens { field_of_view: CameraLens::default_field_of_view(), aperture_radius: 0.0, focus_distance: 1.0, aperture: Aperture::Circular },
+            "orthographic" => CameraLens::Orthographic { height: 2.0 },
+            "fisheye" => CameraLens::Fisheye { projection: FisheyeProjection::Equidistant, field_of_view: 180f64.to_radians() },
+            "sphere" => CameraLens::Sphere,
+            "panorama" => CameraLens::Panorama { height: 2.0, radius: 1.0 },
+            "cube_map" => CameraLens::CubeMap(CubeMapLayout::HorizontalCross),
+            _ => return None
+        };
+
+        Some(lens)
+    }
+
+    pub fn with_field_of_view(self, radians: f64) -> CameraLens {
+        match self {
+            CameraLens::Perspective(_) => CameraLens::Perspective(FieldOfView::Vertical(radians)),
+            CameraLens::ThinLens { aperture_radius, focus_distance, aperture, .. } => {
+                CameraLens::ThinLens { field_of_view: FieldOfView::Vertical(radians), aperture_radius, focus_distance, aperture }
+            }
+            CameraLens::Fisheye { projection, .. } => CameraLens::Fisheye { projection, field_of_view: radians },
+            lens => lens
+        }
+    }
+
+    pub fn lens_shader(&self, aspect_ratio: f64) -> Box<dyn LensShader + Send + Sync> {
+        match self.clone() {
+            CameraLens::Perspective(field_of_view) => Box::new(PlanePerspectiveLensShader::from_field_of_view(field_of_view, aspect_ratio)),
+            CameraLens::Orthographic { height } => Box::new(PlaneOrthographicalLensShader::new(Vector::new(height * aspect_ratio, height, 0.0))),
+            CameraLens::ThinLens { field_of_view, aperture_radius, focus_distance, aperture } => {
+                Box::new(ThinLensShader::new(1.0, field_of_view.film_size(aspect_ratio), aperture_radius, focus_distance, aperture))
+            }
+            CameraLens::Fisheye { projection, field_of_view } => Box::new(FisheyeLensShader::new(projection, field_of_view, aspect_ratio)),
+            CameraLens::Sphere => Box::new(SphereLensShader::full_sphere(1.0)),
+            CameraLens::Panorama { height, radius } => Box::new(PanoramaPerspectiveLensShader::full_amplitude(height, radius)),
+            CameraLens::CubeMap(layout) => Box::new(CubeMapLensShader::new(layout))
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Camera {
+    pub lens: CameraLens,
+    pub transform: Matrix,
+    pub look_at: Option<(Point, Point, Vector)>
+}
+
+impl Camera {
+    pub fn new(lens: CameraLens) -> Camera {
+        Camera { lens, transform: Matrix::IDENTITY, look_at: None }
+    }
+
+    pub fn transformed(self, transform: Transform) -> Camera {
+        self.transformed_by(transform.matrix())
+    }
+
+    pub fn transformed_by(self, matrix: Matrix) -> Camera {
+        Camera { transform: matrix * self.transform, ..self }
+    }
+
+    pub fn lens_shader(&self, width: usize, height: usize) -> Box<dyn LensShader + Send + Sync> {
+        let aspect_ratio = width as f64 / height.max(1) as f64;
+
+        let lens_shader: Box<dyn LensShader + Send + Sync> = Box::new(TransformedLensShader::from_matrix(self.transform, self.lens.lens_shader(aspect_ratio)));
+
+        match self.look_at.and_then(|(eye, target, up)| Transform::look_at(eye, target, up)) {
+            Some(look_at) => Box::new(TransformedLensShader::new(look_at, lens_shader)),
+            _ => lens_shader
+        }
+    }
+}
+
+impl Default for Camera {
+    fn default() -> Camera {
+        Camera::new(CameraLens::Perspective(CameraLens::default_field_of_view()))
+    }
+}
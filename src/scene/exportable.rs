@@ -0,0 +1,101 @@
+use std::sync::Arc;
+use crate::math::float::Float;
+use crate::renderer::environment::Environment;
+use crate::renderer::environment::constant_environment::ConstantEnvironment;
+use crate::renderer::environment::gradient_environment::GradientEnvironment;
+use crate::renderer::environment::image_environment::ImageEnvironment;
+use crate::renderer::environment::sky_environment::SkyEnvironment;
+use crate::scene::scene_exporter::SceneExporter;
+use crate::scene::scene_graph::Geometry;
+use crate::scene::statement::{Statement, Value};
+use crate::surfaces::mesh::Mesh;
+use crate::surfaces::sphere::Sphere;
+use crate::surfaces::surface::Surface;
+
+pub trait ExportableSurface: Surface + Send + Sync {
+    fn export(&self, exporter: &mut SceneExporter) -> Vec<Statement>;
+}
+
+pub trait ExportableEnvironment: Environment + Send + Sync {
+    fn export(&self, exporter: &mut SceneExporter) -> Option<Statement>;
+}
+
+impl<S: ExportableSurface + ?Sized> ExportableSurface for Box<S> {
+    fn export(&self, exporter: &mut SceneExporter) -> Vec<Statement> {
+        (**self).export(exporter)
+    }
+}
+
+impl<S: ExportableSurface + ?Sized> ExportableSurface for Arc<S> {
+    fn export(&self, exporter: &mut SceneExporter) -> Vec<Statement> {
+        (**self).export(exporter)
+    }
+}
+
+impl ExportableSurface for Sphere {
+    fn export(&self, exporter: &mut SceneExporter) -> Vec<Statement> {
+        let material = exporter.material(&self.material);
+        vec![Statement::new("sphere", Vec::new()).with_block(vec![Statement::numbers("radius", &[self.radius]), material])]
+    }
+}
+
+impl<T: Float> ExportableSurface for Mesh<T> where Mesh<T>: Surface + Send + Sync {
+    fn export(&self, exporter: &mut SceneExporter) -> Vec<Statement> {
+        exporter.warn(format!("a triangle mesh with {} triangles cannot be exported", self.triangle_count()));
+        Vec::new()
+    }
+}
+
+impl ExportableSurface for Geometry {
+    fn export(&self, exporter: &mut SceneExporter) -> Vec<Statement> {
+        exporter.geometry(self.node())
+    }
+}
+
+impl<E: ExportableEnvironment + ?Sized> ExportableEnvironment for Box<E> {
+    fn export(&self, exporter: &mut SceneExporter) -> Option<Statement> {
+        (**self).export(exporter)
+    }
+}
+
+impl ExportableEnvironment for ConstantEnvironment {
+    fn export(&self, _exporter: &mut SceneExporter) -> Option<Statement> {
+        Some(Statement::new("environment", vec![Value::Word("constant".to_string())]).with_block(vec![SceneExporter::color("color", self.color)]))
+    }
+}
+
+impl ExportableEnvironment for GradientEnvironment {
+    fn export(&self, _exporter: &mut SceneExporter) -> Option<Statement> {
+        Some(Statement::new("environment", vec![Value::Word("gradient".to_string())]).with_block(vec![
+            SceneExporter::color("zenith", self.zenith),
+            SceneExporter::color("horizon", self.horizon),
+            SceneExporter::color("ground", self.ground)
+        ]))
+    }
+}
+
+impl ExportableEnvironment for ImageEnvironment {
+    fn export(&self, exporter: &mut SceneExporter) -> Option<Statement> {
+        match self.path() {
+            Some(path) => Some(Statement::new("environment", vec![Value::Word("image".to_string()), exporter.path(path)])
+                .with_block(vec![Statement::numbers("intensity", &[self.intensity()])])),
+            _ => {
+                exporter.warn("the image environment is not read from a file and cannot be exported");
+                None
+            }
+        }
+    }
+}
+
+impl ExportableEnvironment for SkyEnvironment {
+    fn export(&self, _exporter: &mut SceneExporter) -> Option<Statement> {
+        let sun = self.sun_direction();
+
+        Some(Statement::new("environment", vec![Value::Word("sky".to_string())]).with_block(vec![
+            Statement::numbers("sun", &[sun.x, sun.y, sun.z]),
+            Statement::numbers("turbidity", &[self.turbidity()]),
+            SceneExporter::color("albedo", self.ground_albedo()),
+            Statement::numbers("intensity", &[self.intensity()])
+        ]))
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use gltf::camera::Projection;
+use gltf::image::Format;
+use gltf::khr_lights_punctual::Kind;
+use gltf::material::AlphaMode;
+use gltf::mesh::Mode;
+use gltf::texture::{MagFilter, WrappingMode};
+use crate::math::color::Color;
+use crate::math::matrix::Matrix;
+use crate::math::point::Point;
+use crate::math::transformation::quaternion::Quaternion;
+use crate::math::transformation::scale::Scale;
+use crate::math::transformation::transform::Transform;
+use crate::math::transformation::transformation::Transformation;
+use crate::math::transformation::translation::Translation;
+use crate::math::vector::Vector;
+use crate::renderer::image::Image;
+use crate::renderer::lens_shader::field_of_view::FieldOfView;
+use crate::renderer::light::Light;
+use crate::scene::camera::{Camera, CameraLens};
+use crate::scene::scene_graph::{Geometry, SceneNode, SharedSurface};
+use crate::surfaces::material::Material;
+use crate::surfaces::mesh::Mesh;
+use crate::textures::image_texture::{Filter, ImageTexture, WrapMode};
+use crate::textures::texture::Texture;
+
+pub const SUPPORTED_EXTENSIONS: [&str; 2] = ["KHR_lights_punctual", "KHR_materials_emissive_strength"];
+
+pub struct GltfImport {
+    pub nodes: Vec<SceneNode>,
+    pub cameras: Vec<Camera>,
+    pub lights: Vec<Light>,
+    pub skipped_extensions: Vec<String>,
+    pub warnings: Vec<String>
+}
+
+pub fn import<P: AsRef<Path>>(path: P) -> io::Result<GltfImport> {
+    let (document, buffers, images) = gltf::import(path).map_err(|error| match error {
+        gltf::Error::Io(error) => error,
+        error => io::Error::new(io::ErrorKind::InvalidData, error.to_string())
+    })?;
+
+    let mut importer = GltfImporter {
+        buffers,
+        images,
+        textures: HashMap::new(),
+        materials: HashMap::new(),
+        meshes: HashMap::new(),
+        import: GltfImport { nodes: Vec::new(), cameras: Vec::new(), lights: Vec::new(), skipped_extensions: Vec::new(), warnings: Vec::new() }
+    };
+
+    importer.import.skipped_extensions = document.extensions_used()
+        .filter(|extension| !SUPPORTED_EXTENSIONS.contains(extension))
+        .map(|extension| extension.to_string())
+        .collect();
+
+    if document.animations().next().is_some() {
+        importer.import.warnings.push("animations are not imported".to_string());
+    }
+
+    match document.default_scene().or_else(|| document.scenes().next()) {
+        Some(scene) => for node in scene.nodes() {
+            let node = importer.node(&node, Matrix::IDENTITY);
+            importer.import.nodes.push(node);
+        },
+        _ => importer.import.warnings.push("the file contains no scene".to_string())
+    }
+
+    Ok(importer.import)
+}
+
+struct GltfImporter {
+    buffers: Vec<gltf::buffer::Data>,
+    images: Vec<gltf::image::Data>,
+    textures: HashMap<(usize, bool), Arc<dyn Texture + Send + Sync>>,
+    materials: HashMap<Option<usize>, Material>,
+    meshes: HashMap<usize, SharedSurface>,
+    import: GltfImport
+}
+
+impl GltfImporter {
+    fn node(&mut self, node: &gltf::Node, parent: Matrix) -> SceneNode {
+        let (translation, rotation, scale) = node.transform().decomposed();
+        let transform = Transform::from_parts(
+            Translation::new(vector(translation)),
+            quaternion(rotation),
+            Scale::new(vector(scale))
+        );
+
+        let mut scene_node = match node.name() {
+            Some(name) => SceneNode::named(name),
+            _ => SceneNode::new()
+        }.transformed(transform);
+
+        let world = parent * scene_node.transform();
+
+        if let Some(mesh) = node.mesh() {
+            scene_node.geometry = Some(self.mesh(&mesh));
+        }
+
+        if node.skin().is_some() {
+            self.warn(format!("skin of node {} is ignored", node_label(node)));
+        }
+
+        if let Some(camera) = node.camera() {
+            let lens = match camera.projection() {
+                Projection::Perspective(perspective) => CameraLens::Perspective(FieldOfView::Vertical(perspective.yfov() as f64)),
+                Projection::Orthographic(orthographic) => CameraLens::Orthographic { height: 2.0 * orthographic.ymag() as f64 }
+            };
+
+            let flip = Transform::scale(Vector::new(1.0, -1.0, -1.0)).matrix();
+            self.import.cameras.push(Camera { transform: world * flip, ..Camera::new(lens) });
+        }
+
+        if let Some(light) = node.light() {
+            let color = Color::solid(light.color()[0] as f64, light.color()[1] as f64, light.color()[2] as f64) * light.intensity() as f64;
+            let range = light.range().map(|range| range as f64);
+
+            let light = match light.kind() {
+                Kind::Directional => Light::directional(Vector::BACKWARD, color),
+                Kind::Point => Light::Point { position: Point::ZERO, color, range },
+                Kind::Spot { inner_cone_angle, outer_cone_angle } => Light::Spot {
+                    position: Point::ZERO,
+                    direction: Vector::BACKWARD,
+                    color,
+                    range,
+                    inner_cone: inner_cone_angle as f64,
+                    outer_cone: outer_cone_angle as f64
+                }
+            };
+
+            self.import.lights.push(light.transformed(world));
+        }
+
+        for child in node.children() {
+            let child = self.node(&child, world);
+            scene_node.children.push(child);
+        }
+
+        scene_node
+    }
+
+    fn mesh(&mut self, mesh: &gltf::Mesh) -> SharedSurface {
+        if let Some(surface) = self.meshes.get(&mesh.index()) {
+            return surface.clone();
+        }
+
+        let mut surfaces: Vec<SharedSurface> = Vec::new();
+
+        for primitive in mesh.primitives() {
+            if let Some(mesh) = self.primitive(mesh, &primitive) {
+                surfaces.push(Arc::new(mesh));
+            }
+        }
+
+        let surface: SharedSurface = match surfaces.len() {
+            1 => surfaces.remove(0),
+            _ => Arc::new(Geometry::new(surfaces.into_iter().fold(SceneNode::new(), |node, surface| node.with_child(SceneNode::instance(surface)))))
+        };
+
+        self.meshes.insert(mesh.index(), surface.clone());
+        surface
+    }
+
+    fn primitive(&mut self, mesh: &gltf::Mesh, primitive: &gltf::Primitive) -> Option<Mesh<f32>> {
+        let label = format!("primitive {} of mesh {}", primitive.index(), mesh.name().map_or(mesh.index().to_string(), |name| format!("'{}'", name)));
+        let reader = primitive.reader(|buffer| Some(&self.buffers[buffer.index()]));
+
+        let positions = match reader.read_positions() {
+            Some(positions) => positions.map(|position| Point::new(position[0], position[1], position[2])).collect::<Vec<Point<f32>>>(),
+            _ => {
+                self.warn(format!("{} has no positions and is skipped", label));
+                return None;
+            }
+        };
+
+        let normals = reader.read_normals().map(|normals| normals.map(|normal| Vector::new(normal[0], normal[1], normal[2])).collect::<Vec<Vector<f32>>>());
+        let uvs = reader.read_tex_coords(0).map(|uvs| uvs.into_f32().map(|uv| (uv[0], uv[1])).collect::<Vec<(f32, f32)>>());
+        let indices = match reader.read_indices() {
+            Some(indices) => indices.into_u32().map(|index| index as usize).collect::<Vec<usize>>(),
+            _ => (0..positions.len()).collect()
+        };
+
+        if primitive.morph_targets().len() > 0 {
+            self.warn(format!("morph targets of {} are ignored", label));
+        }
+
+        let triangles = match primitive.mode() {
+            Mode::Triangles => indices.chunks_exact(3).map(|triangle| [triangle[0], triangle[1], triangle[2]]).collect::<Vec<[usize; 3]>>(),
+            Mode::TriangleStrip => indices.windows(3).enumerate()
+                .map(|(index, strip)| if index % 2 == 0 { [strip[0], strip[1], strip[2]] } else { [strip[1], strip[0], strip[2]] })
+                .collect(),
+            Mode::TriangleFan => indices.windows(2).skip(1).map(|fan| [indices[0], fan[0], fan[1]]).collect(),
+            mode => {
+                self.warn(format!("{} uses unsupported mode {:?} and is skipped", label, mode));
+                return None;
+            }
+        };
+
+        let material = self.material(&primitive.material());
+
+        match Mesh::new(positions, normals, uvs, triangles, material) {
+            Some(mesh) => Some(mesh),
+            _ => {
+                self.warn(format!("{} has inconsistent vertex data and is skipped", label));
+                None
+            }
+        }
+    }
+
+    fn material(&mut self, material: &gltf::Material) -> Material {
+        if let Some(converted) = self.materials.get(&material.index()) {
+            return converted.clone();
+        }
+
+        let label = material.name().map_or_else(|| format!("material {}", material.index().map_or("default".to_string(), |index| index.to_string())), |name| format!("material '{}'", name));
+        let pbr = material.pbr_metallic_roughness();
+        let base_color = pbr.base_color_factor();
+
+        let mut converted = Material::new(Color::new(base_color[0] as f64, base_color[1] as f64, base_color[2] as f64, base_color[3] as f64));
+        converted.metallic = pbr.metallic_factor() as f64;
+        converted.roughness = pbr.roughness_factor() as f64;
+
+        let emissive = material.emissive_factor();
+        let emissive_strength = material.emissive_strength().unwrap_or(1.0) as f64;
+        converted.emission = Color::solid(emissive[0] as f64, emissive[1] as f64, emissive[2] as f64) * emissive_strength;
+
+        let textures = [
+            ("base color", pbr.base_color_texture()),
+            ("metallic roughness", pbr.metallic_roughness_texture()),
+            ("emissive", material.emissive_texture())
+        ];
+
+        for (kind, info) in textures {
+            let info = match info {
+                Some(info) => info,
+                _ => continue
+            };
+
+            if info.tex_coord() != 0 {
+                self.warn(format!("{} {} texture uses texture coordinate set {}, only set 0 is supported", label, kind, info.tex_coord()));
+            }
+
+            let texture = Some(self.texture(&info.texture(), kind != "metallic roughness"));
+
+            match kind {
+                "base color" => converted.diffuse_texture = texture,
+                "metallic roughness" => converted.metallic_roughness_texture = texture,
+                _ => converted.emission_texture = texture
+            }
+        }
+
+        if let Some(normal_texture) = material.normal_texture() {
+            let texture = normal_texture.texture();
+            let image = normal_map(&self.images[texture.source().index()], normal_texture.scale() as f64);
+            converted.normal_map = Some(Arc::new(ImageTexture::new(image, wrap_mode(texture.sampler().wrap_s()), filter(texture.sampler().mag_filter()))));
+        }
+
+        if material.occlusion_texture().is_some() {
+            self.warn(format!("{} occlusion texture is ignored", label));
+        }
+
+        if material.alpha_mode() != AlphaMode::Opaque {
+            self.warn(format!("{} alpha mode {:?} is rendered as opaque", label, material.alpha_mode()));
+        }
+
+        self.materials.insert(material.index(), converted.clone());
+        converted
+    }
+
+    fn texture(&mut self, texture: &gltf::Texture, srgb: bool) -> Arc<dyn Texture + Send + Sync> {
+        if let Some(converted) = self.textures.get(&(texture.index(), srgb)) {
+            return converted.clone();
+        }
+
+        let sampler = texture.sampler();
+        let data = &self.images[texture.source().index()];
+        let image = if srgb { image(data, srgb_to_linear) } else { image(data, |color| color) };
+        let converted: Arc<dyn Texture + Send + Sync> = Arc::new(ImageTexture::new(image, wrap_mode(sampler.wrap_s()), filter(sampler.mag_filter())));
+
+        self.textures.insert((texture.index(), srgb), converted.clone());
+        converted
+    }
+
+    fn warn(&mut self, warning: String) {
+        self.import.warnings.push(warning);
+    }
+}
+
+fn node_label(node: &gltf::Node) -> String {
+    node.name().map_or(node.index().to_string(), |name| format!("'{}'", name))
+}
+
+fn vector(components: [f32; 3]) -> Vector {
+    Vector::new(components[0] as f64, components[1] as f64, components[2] as f64)
+}
+
+fn quaternion(components: [f32; 4]) -> Quaternion {
+    let [x, y, z, w] = components.map(|component| component as f64);
+    Quaternion::new(w, x, y, z).normalized().unwrap_or(Quaternion::IDENTITY)
+}
+
+fn wrap_mode(mode: WrappingMode) -> WrapMode {
+    match mode {
+        WrappingMode::Repeat => WrapMode::Repeat,
+        WrappingMode::MirroredRepeat => WrapMode::MirroredRepeat,
+        WrappingMode::ClampToEdge => WrapMode::Clamp
+    }
+}
+
+fn filter(filter: Option<MagFilter>) -> Filter {
+    match filter {
+        Some(MagFilter::Nearest) => Filter::Nearest,
+        _ => Filter::Bilinear
+    }
+}
+
+fn image<F: Fn(Color) -> Color>(data: &gltf::image::Data, convert: F) -> Image {
+    let (channels, bytes) = match data.format {
+        Format::R8 => (1, 1),
+        Format::R8G8 => (2, 1),
+        Format::R8G8B8 => (3, 1),
+        Format::R8G8B8A8 => (4, 1),
+        Format::R16 => (1, 2),
+        Format::R16G16 => (2, 2),
+        Format::R16G16B16 => (3, 2),
+        Format::R16G16B16A16 => (4, 2),
+        Format::R32G32B32FLOAT => (3, 4),
+        Format::R32G32B32A32FLOAT => (4, 4)
+    };
+
+    let component = |bytes: &[u8]| match bytes.len() {
+        1 => bytes[0] as f64 / u8::MAX as f64,
+        2 => u16::from_le_bytes([bytes[0], bytes[1]]) as f64 / u16::MAX as f64,
+        _ => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64
+    };
+
+    let pixels = data.pixels.chunks_exact(channels * bytes)
+        .map(|pixel| {
+            let values = pixel.chunks_exact(bytes).map(component).collect::<Vec<f64>>();
+
+            convert(match channels {
+                1 => Color::solid(values[0], values[0], values[0]),
+                2 => Color::new(values[0], values[0], values[0], values[1]),
+                3 => Color::solid(values[0], values[1], values[2]),
+                _ => Color::new(values[0], values[1], values[2], values[3])
+            })
+        })
+        .collect();
+
+    Image::from_pixels(data.width as usize, data.height as usize, pixels)
+}
+
+fn srgb_to_linear(color: Color) -> Color {
+    let linear = |value: f64| if value <= 0.04045 { value / 12.92 } else { ((value + 0.055) / 1.055).powf(2.4) };
+    Color::new(linear(color.red), linear(color.green), linear(color.blue), color.alpha)
+}
+
+fn normal_map(data: &gltf::image::Data, scale: f64) -> Image {
+    image(data, |color| {
+        let (x, y) = ((2.0 * color.red - 1.0) * scale, (1.0 - 2.0 * color.green) * scale);
+        Color::solid(0.5 * x + 0.5, 0.5 * y + 0.5, color.blue)
+    })
+}
@@ -0,0 +1,14 @@
+pub mod scene;
+pub mod scene_error;
+pub mod tokenizer;
+pub mod statement;
+pub mod camera;
+pub mod scene_pixel_shader;
+pub mod scene_loader;
+pub mod scene_graph;
+pub mod scene_exporter;
+pub mod exportable;
+
+#[cfg(feature = "gltf")]
+pub mod gltf_importer;
+
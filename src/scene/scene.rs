@@ -0,0 +1,106 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::math::color::Color;
+use crate::renderer::image::Image;
+use crate::renderer::environment::gradient_environment::GradientEnvironment;
+use crate::renderer::lens_shader::LensShader;
+use crate::renderer::pixel_shader::default_pixel_shader::DefaultPixelShader;
+use crate::renderer::ray_shader::default_ray_shader::DefaultRayShader;
+use crate::renderer::renderer::Renderer;
+use crate::scene::camera::Camera;
+use crate::scene::scene_error::SceneError;
+use crate::scene::scene_exporter::SceneExporter;
+use crate::scene::scene_graph::SceneGraph;
+use crate::scene::scene_loader;
+use crate::scene::scene_pixel_shader::ScenePixelShader;
+use crate::scene::statement::Statement;
+use crate::surfaces::multiple_surfaces::MultipleSurfaces;
+
+pub struct Scene {
+    pub width: usize,
+    pub height: usize,
+    pub samples_per_pixel: usize,
+    pub fill_color: Color,
+    pub shutter: (f64, f64),
+    pub max_reflections: usize,
+    pub camera: Camera,
+    pub pixel_shader: ScenePixelShader,
+    pub graph: SceneGraph,
+    pub warnings: Vec<String>
+}
+
+impl Scene {
+    pub fn new() -> Scene {
+        Scene {
+            width: 1920,
+            height: 1080,
+            samples_per_pixel: 1,
+            fill_color: Color::BLACK,
+            shutter: (0.0, 0.0),
+            max_reflections: 5,
+            camera: Camera::default(),
+            pixel_shader: ScenePixelShader::Default(DefaultPixelShader::new(Box::new(GradientEnvironment::sky()))),
+            graph: SceneGraph::new(),
+            warnings: Vec::new()
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Scene, SceneError> {
+        #[cfg(feature = "gltf")]
+        if scene_loader::is_gltf(path.as_ref()) {
+            return scene_loader::load_gltf(path.as_ref());
+        }
+
+        let source = fs::read_to_string(path.as_ref())?;
+        let base_directory = path.as_ref().parent().unwrap_or(Path::new("."));
+
+        Scene::parse(&source, base_directory)
+    }
+
+    pub fn parse<P: AsRef<Path>>(source: &str, base_directory: P) -> Result<Scene, SceneError> {
+        scene_loader::load(&Statement::parse_all(source)?, base_directory.as_ref())
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<Vec<String>> {
+        let base_directory = path.as_ref().parent().unwrap_or(Path::new("."));
+        let mut exporter = SceneExporter::relative_to(base_directory);
+        let statements = exporter.scene(self);
+
+        fs::write(path.as_ref(), Statement::format_all(&statements))?;
+        Ok(exporter.warnings().to_vec())
+    }
+
+    pub fn world(&self) -> MultipleSurfaces {
+        self.graph.surface()
+    }
+
+    pub fn image(&self) -> Image {
+        Image::new(self.width, self.height)
+    }
+
+    pub fn renderer(&self) -> Renderer<Box<dyn LensShader + Send + Sync>, DefaultRayShader, &ScenePixelShader> {
+        let mut renderer = Renderer::new(
+            self.camera.lens_shader(self.width, self.height),
+            DefaultRayShader::new(self.max_reflections),
+            &self.pixel_shader
+        );
+
+        renderer.set_samples_per_pixel(self.samples_per_pixel);
+        renderer.set_fill_color(self.fill_color);
+        renderer.set_shutter(self.shutter.0, self.shutter.1);
+        renderer
+    }
+
+    pub fn render(&self) -> Image {
+        let mut image = self.image();
+        self.renderer().render(&mut image, &self.world());
+        image
+    }
+}
+
+impl Default for Scene {
+    fn default() -> Scene {
+        Scene::new()
+    }
+}
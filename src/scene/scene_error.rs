@@ -0,0 +1,45 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum SceneError {
+    Io(std::io::Error),
+    Invalid { line: usize, message: String }
+}
+
+impl SceneError {
+    pub fn at<M: Into<String>>(line: usize, message: M) -> SceneError {
+        SceneError::Invalid { line, message: message.into() }
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            SceneError::Invalid { line, .. } => Some(*line),
+            _ => None
+        }
+    }
+}
+
+impl Display for SceneError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SceneError::Io(error) => write!(f, "{}", error),
+            SceneError::Invalid { line, message } => write!(f, "line {}: {}", line, message)
+        }
+    }
+}
+
+impl Error for SceneError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SceneError::Io(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<std::io::Error> for SceneError {
+    fn from(error: std::io::Error) -> SceneError {
+        SceneError::Io(error)
+    }
+}
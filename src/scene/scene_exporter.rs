@@ -0,0 +1,397 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::math::color::Color;
+use crate::math::matrix::Matrix;
+use crate::renderer::lens_shader::aperture::Aperture;
+use crate::renderer::lens_shader::cube_map_lens_shader::CubeMapLayout;
+use crate::renderer::lens_shader::field_of_view::{FieldOfView, Sensor};
+use crate::renderer::lens_shader::fisheye_lens_shader::FisheyeProjection;
+use crate::scene::camera::{Camera, CameraLens};
+use crate::scene::exportable::{ExportableEnvironment, ExportableSurface};
+use crate::scene::scene::Scene;
+use crate::scene::scene_graph::SceneNode;
+use crate::scene::scene_pixel_shader::ScenePixelShader;
+use crate::scene::statement::{Statement, Value};
+use crate::surfaces::material::Material;
+use crate::textures::texture::Texture;
+
+pub struct SceneExporter {
+    base_directory: Option<PathBuf>,
+    material_override: Option<Material>,
+    geometries: HashMap<*const (), String>,
+    warnings: Vec<String>
+}
+
+impl SceneExporter {
+    pub fn new() -> SceneExporter {
+        SceneExporter { base_directory: None, material_override: None, geometries: HashMap::new(), warnings: Vec::new() }
+    }
+
+    pub fn relative_to<P: AsRef<Path>>(base_directory: P) -> SceneExporter {
+        SceneExporter { base_directory: Some(base_directory.as_ref().to_path_buf()), ..SceneExporter::new() }
+    }
+
+    pub fn warn<M: Into<String>>(&mut self, message: M) {
+        self.warnings.push(message.into());
+    }
+
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    pub fn scene(&mut self, scene: &Scene) -> Vec<Statement> {
+        let mut image = vec![
+            Statement::numbers("width", &[scene.width as f64]),
+            Statement::numbers("height", &[scene.height as f64]),
+            Statement::numbers("samples", &[scene.samples_per_pixel as f64])
+        ];
+
+        if !SceneExporter::is_color(scene.fill_color, Color::BLACK) {
+            image.push(SceneExporter::color("fill", scene.fill_color));
+        }
+
+        if scene.shutter != (0.0, 0.0) {
+            image.push(Statement::numbers("shutter", &[scene.shutter.0, scene.shutter.1]));
+        }
+
+        let mut statements = vec![
+            Statement::new("image", Vec::new()).with_block(image),
+            self.camera(&scene.camera),
+            Statement::new("ray_shader", Vec::new()).with_block(vec![Statement::numbers("max_reflections", &[scene.max_reflections as f64])])
+        ];
+
+        statements.extend(self.pixel_shader(&scene.pixel_shader));
+
+        let mut uses = HashMap::new();
+        SceneExporter::count_geometry_uses(&scene.graph.root, &mut uses);
+
+        let mut nodes = Vec::new();
+        let mut names = HashSet::new();
+        let root = &scene.graph.root;
+
+        if root.geometry.is_none() && root.material.is_none() && root.transform() == Matrix::IDENTITY {
+            for child in root.children.iter() {
+                nodes.extend(self.node(child, None, &uses, &mut names, &mut statements));
+            }
+        } else {
+            nodes.extend(self.node(root, None, &uses, &mut names, &mut statements));
+        }
+
+        statements.extend(nodes);
+        statements
+    }
+
+    pub fn surface<S: ExportableSurface + ?Sized>(&mut self, surface: &S) -> Vec<Statement> {
+        surface.export(self)
+    }
+
+    pub fn surface_with_material<S: ExportableSurface + ?Sized>(&mut self, material: &Material, surface: &S) -> Vec<Statement> {
+        if self.material_override.is_some() {
+            return surface.export(self);
+        }
+
+        self.material_override = Some(material.clone());
+        let statements = surface.export(self);
+        self.material_override = None;
+
+        statements
+    }
+
+    pub fn geometry(&mut self, node: &SceneNode) -> Vec<Statement> {
+        self.flattened(node, Matrix::IDENTITY, None)
+    }
+
+    pub fn transformed(&mut self, matrix: Matrix, mut statements: Vec<Statement>) -> Vec<Statement> {
+        let transform = match SceneExporter::transform(matrix) {
+            Some(transform) => transform,
+            _ => return statements
+        };
+
+        if statements.len() == 1 {
+            let mut statement = statements.remove(0);
+            statement.block.get_or_insert_with(Vec::new).push(transform);
+            return vec![statement];
+        }
+
+        statements.insert(0, transform);
+        vec![Statement::new("group", Vec::new()).with_block(statements)]
+    }
+
+    pub fn material(&mut self, material: &Material) -> Statement {
+        let material = self.material_override.clone().unwrap_or_else(|| material.clone());
+        let mut statements = vec![SceneExporter::color("diffuse", material.diffuse)];
+
+        let textures = [
+            ("texture", &material.diffuse_texture),
+            ("normal_map", &material.normal_map),
+            ("bump_map", &material.bump_map),
+            ("emission_texture", &material.emission_texture),
+            ("metallic_roughness_texture", &material.metallic_roughness_texture)
+        ];
+
+        for (name, texture) in textures {
+            if let Some(texture) = texture {
+                statements.extend(self.texture(name, texture));
+            }
+        }
+
+        if material.bump_map.is_some() {
+            statements.push(Statement::numbers("bump_strength", &[material.bump_strength]));
+        }
+
+        if !SceneExporter::is_color(material.emission, Color::ZERO) {
+            statements.push(SceneExporter::color("emission", material.emission));
+        }
+
+        if material.metallic != 1.0 {
+            statements.push(Statement::numbers("metallic", &[material.metallic]));
+        }
+
+        if material.roughness != 0.0 {
+            statements.push(Statement::numbers("roughness", &[material.roughness]));
+        }
+
+        Statement::new("material", Vec::new()).with_block(statements)
+    }
+
+    pub fn path(&self, path: &Path) -> Value {
+        let path = match &self.base_directory {
+            Some(base_directory) => match path.strip_prefix(base_directory) {
+                Ok(relative) => relative.to_path_buf(),
+                _ => path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+            },
+            _ => path.to_path_buf()
+        };
+
+        Value::Text(path.to_string_lossy().replace('\\', "/"))
+    }
+
+    pub fn color<N: Into<String>>(name: N, color: Color) -> Statement {
+        if color.alpha == 1.0 {
+            Statement::numbers(name, &[color.red, color.green, color.blue])
+        } else {
+            Statement::numbers(name, &[color.red, color.green, color.blue, color.alpha])
+        }
+    }
+
+    pub fn transform(matrix: Matrix) -> Option<Statement> {
+        if matrix == Matrix::IDENTITY {
+            return None;
+        }
+
+        let linear = [matrix.a, matrix.b, matrix.c, matrix.e, matrix.f, matrix.g, matrix.i, matrix.j, matrix.k];
+
+        if linear == [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0] {
+            return Some(Statement::numbers("translate", &[matrix.d, matrix.h, matrix.l]));
+        }
+
+        Some(Statement::numbers("matrix", &[
+            matrix.a, matrix.b, matrix.c, matrix.d,
+            matrix.e, matrix.f, matrix.g, matrix.h,
+            matrix.i, matrix.j, matrix.k, matrix.l
+        ]))
+    }
+
+    fn is_color(a: Color, b: Color) -> bool {
+        a.red == b.red && a.green == b.green && a.blue == b.blue && a.alpha == b.alpha
+    }
+
+    fn texture(&mut self, name: &str, texture: &Arc<dyn Texture + Send + Sync>) -> Option<Statement> {
+        match texture.source_path() {
+            Some(path) => Some(Statement::new(name, vec![self.path(path)])),
+            _ => {
+                self.warn(format!("'{}' is not read from a file and cannot be exported", name));
+                None
+            }
+        }
+    }
+
+    fn camera(&mut self, camera: &Camera) -> Statement {
+        let (kind, mut statements) = match &camera.lens {
+            CameraLens::Perspective(field_of_view) => ("perspective", self.field_of_view(*field_of_view)),
+            CameraLens::ThinLens { field_of_view, aperture_radius, focus_distance, aperture } => {
+                let mut statements = self.field_of_view(*field_of_view);
+                statements.push(Statement::numbers("aperture_radius", &[*aperture_radius]));
+                statements.push(Statement::numbers("focus_distance", &[*focus_distance]));
+
+                match aperture {
+                    Aperture::Circular => (),
+                    Aperture::Polygonal { blades, rotation } => statements.push(Statement::numbers("blades", &[*blades as f64, rotation.to_degrees()])),
+                    Aperture::Image(_) => self.warn("image apertures cannot be exported, the camera uses a circular aperture")
+                }
+
+                ("thin_lens", statements)
+            }
+            CameraLens::Orthographic { height } => ("orthographic", vec![Statement::numbers("height", &[*height])]),
+            CameraLens::Fisheye { projection, field_of_view } => {
+                let projection = match projection {
+                    FisheyeProjection::Equidistant => "equidistant",
+                    FisheyeProjection::Equisolid => "equisolid",
+                    FisheyeProjection::Orthographic => "orthographic",
+                    FisheyeProjection::Stereographic => "stereographic"
+                };
+
+                ("fisheye", vec![
+                    Statement::new("projection", vec![Value::Word(projection.to_string())]),
+                    Statement::numbers("fov", &[field_of_view.to_degrees()])
+                ])
+            }
+            CameraLens::Sphere => ("sphere", Vec::new()),
+            CameraLens::Panorama { height, radius } => ("panorama", vec![Statement::numbers("height", &[*height]), Statement::numbers("radius", &[*radius])]),
+            CameraLens::CubeMap(layout) => {
+                let layout = match layout {
+                    CubeMapLayout::HorizontalStrip => "horizontal_strip",
+                    CubeMapLayout::VerticalStrip => "vertical_strip",
+                    CubeMapLayout::HorizontalCross => "horizontal_cross",
+                    CubeMapLayout::VerticalCross => "vertical_cross"
+                };
+
+                ("cube_map", vec![Statement::new("layout", vec![Value::Word(layout.to_string())])])
+            }
+        };
+
+        statements.extend(SceneExporter::transform(camera.transform));
+
+        if let Some((eye, target, up)) = camera.look_at {
+            statements.push(Statement::numbers("look_at", &[eye.x, eye.y, eye.z, target.x, target.y, target.z, up.x, up.y, up.z]));
+        }
+
+        Statement::new("camera", vec![Value::Word(kind.to_string())]).with_block(statements)
+    }
+
+    fn field_of_view(&mut self, field_of_view: FieldOfView) -> Vec<Statement> {
+        let statement = match field_of_view {
+            FieldOfView::Vertical(radians) => Statement::numbers("vertical_fov", &[radians.to_degrees()]),
+            FieldOfView::Horizontal(radians) => Statement::numbers("horizontal_fov", &[radians.to_degrees()]),
+            FieldOfView::FocalLength { focal_length, sensor } => {
+                let name = match sensor {
+                    Sensor::FULL_FRAME => "full_frame",
+                    Sensor::APS_C => "aps_c",
+                    Sensor::MICRO_FOUR_THIRDS => "micro_four_thirds",
+                    _ => return vec![Statement::numbers("focal_length", &[focal_length, sensor.width, sensor.height])]
+                };
+
+                Statement::new("focal_length", vec![Value::Number(focal_length), Value::Word(name.to_string())])
+            }
+        };
+
+        vec![statement]
+    }
+
+    fn pixel_shader(&mut self, pixel_shader: &ScenePixelShader) -> Vec<Statement> {
+        let (environment, pixel_shader) = match pixel_shader {
+            ScenePixelShader::Default(pixel_shader) => (pixel_shader.environment().export(self), Statement::new("pixel_shader", vec![Value::Word("default".to_string())])),
+            ScenePixelShader::EnvironmentLight(pixel_shader) => {
+                if !pixel_shader.lights().is_empty() {
+                    self.warn(format!("{} light(s) cannot be exported", pixel_shader.lights().len()));
+                }
+
+                let statement = Statement::new("pixel_shader", vec![Value::Word("environment_light".to_string())])
+                    .with_block(vec![Statement::numbers("samples", &[pixel_shader.light_samples() as f64])]);
+
+                (pixel_shader.environment().export(self), statement)
+            }
+        };
+
+        environment.into_iter().chain([pixel_shader]).collect()
+    }
+
+    fn flattened(&mut self, node: &SceneNode, parent: Matrix, inherited: Option<&Material>) -> Vec<Statement> {
+        let transform = parent * node.transform();
+        let material = node.material.as_ref().or(inherited);
+        let mut statements = Vec::new();
+
+        if let Some(geometry) = &node.geometry {
+            let surface = match material {
+                Some(material) => self.surface_with_material(material, geometry.as_ref()),
+                _ => self.surface(geometry.as_ref())
+            };
+
+            statements.extend(self.transformed(transform, surface));
+        }
+
+        for child in node.children.iter() {
+            statements.extend(self.flattened(child, transform, material));
+        }
+
+        statements
+    }
+
+    fn count_geometry_uses(node: &SceneNode, uses: &mut HashMap<*const (), usize>) {
+        if let Some(geometry) = &node.geometry {
+            *uses.entry(Arc::as_ptr(geometry) as *const ()).or_insert(0) += 1;
+        }
+
+        for child in node.children.iter() {
+            SceneExporter::count_geometry_uses(child, uses);
+        }
+    }
+
+    fn node(&mut self, node: &SceneNode, inherited: Option<&Material>, uses: &HashMap<*const (), usize>, names: &mut HashSet<String>, definitions: &mut Vec<Statement>) -> Vec<Statement> {
+        let material = node.material.as_ref().or(inherited);
+        let mut statements = Vec::new();
+
+        if let Some(geometry) = &node.geometry {
+            let pointer = Arc::as_ptr(geometry) as *const ();
+
+            if uses.get(&pointer).copied().unwrap_or(0) > 1 {
+                let name = match self.geometries.get(&pointer) {
+                    Some(name) => name.clone(),
+                    _ => {
+                        let name = format!("geometry_{}", self.geometries.len() + 1);
+                        let body = self.surface(geometry.as_ref());
+
+                        definitions.push(Statement::new("geometry", vec![Value::Word(name.clone())]).with_block(body));
+                        self.geometries.insert(pointer, name.clone());
+                        name
+                    }
+                };
+
+                statements.push(Statement::new("instance", vec![Value::Word(name)]));
+            } else {
+                match material {
+                    Some(material) => statements.extend(self.surface_with_material(material, geometry.as_ref())),
+                    _ => statements.extend(self.surface(geometry.as_ref()))
+                }
+            }
+        }
+
+        for child in node.children.iter() {
+            statements.extend(self.node(child, material, uses, names, definitions));
+        }
+
+        let name = node.name.as_deref().filter(|name| {
+            let mut characters = name.chars();
+            let valid = characters.next().is_some_and(|first| first.is_alphabetic() || first == '_')
+                && characters.all(|character| character.is_alphanumeric() || character == '_');
+
+            valid && names.insert(name.to_string())
+        });
+
+        if let (Some(original), None) = (&node.name, name) {
+            self.warn(format!("node '{}' is exported without its name, names must be unique words", original));
+        }
+
+        if name.is_none() && node.material.is_none() {
+            return self.transformed(node.transform(), statements);
+        }
+
+        if let Some(material) = &node.material {
+            statements.insert(0, self.material(material));
+        }
+
+        statements.extend(SceneExporter::transform(node.transform()));
+
+        match name {
+            Some(name) => vec![Statement::new("node", vec![Value::Word(name.to_string())]).with_block(statements)],
+            _ => vec![Statement::new("group", Vec::new()).with_block(statements)]
+        }
+    }
+}
+
+impl Default for SceneExporter {
+    fn default() -> SceneExporter {
+        SceneExporter::new()
+    }
+}
@@ -0,0 +1,202 @@
+use std::sync::Arc;
+use crate::math::matrix::Matrix;
+use crate::math::ray::Ray;
+use crate::math::ray_packet::RayPacket;
+use crate::math::transformation::transform::Transform;
+use crate::math::transformation::transformation::Transformation;
+use crate::scene::exportable::ExportableSurface;
+use crate::surfaces::bounding_box::BoundingBox;
+use crate::surfaces::material::Material;
+use crate::surfaces::material_override::MaterialOverride;
+use crate::surfaces::multiple_surfaces::MultipleSurfaces;
+use crate::surfaces::surface::{Surface, SurfacePoint};
+use crate::surfaces::transformed_surface::TransformedSurface;
+
+pub type SharedSurface = Arc<dyn ExportableSurface>;
+
+#[derive(Clone)]
+pub struct SceneNode {
+    pub name: Option<String>,
+    pub geometry: Option<SharedSurface>,
+    pub material: Option<Material>,
+    pub children: Vec<SceneNode>,
+    transform: Matrix,
+    inverse_transform: Matrix
+}
+
+impl SceneNode {
+    pub fn new() -> SceneNode {
+        SceneNode { name: None, geometry: None, material: None, children: Vec::new(), transform: Matrix::IDENTITY, inverse_transform: Matrix::IDENTITY }
+    }
+
+    pub fn named<N: Into<String>>(name: N) -> SceneNode {
+        SceneNode { name: Some(name.into()), ..SceneNode::new() }
+    }
+
+    pub fn instance(geometry: SharedSurface) -> SceneNode {
+        SceneNode { geometry: Some(geometry), ..SceneNode::new() }
+    }
+
+    pub fn with_material(self, material: Material) -> SceneNode {
+        SceneNode { material: Some(material), ..self }
+    }
+
+    pub fn with_child(mut self, child: SceneNode) -> SceneNode {
+        self.children.push(child);
+        self
+    }
+
+    pub fn transformed(self, transform: Transform) -> SceneNode {
+        self.transformed_by(transform.matrix(), transform.inverse_matrix())
+    }
+
+    pub fn transformed_by(self, matrix: Matrix, inverse: Matrix) -> SceneNode {
+        SceneNode {
+            transform: matrix * self.transform,
+            inverse_transform: self.inverse_transform * inverse,
+            ..self
+        }
+    }
+
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform.matrix();
+        self.inverse_transform = transform.inverse_matrix();
+    }
+
+    pub fn transform(&self) -> Matrix {
+        self.transform
+    }
+
+    pub fn inverse_transform(&self) -> Matrix {
+        self.inverse_transform
+    }
+
+    pub fn find(&self, name: &str) -> Option<&SceneNode> {
+        if self.name.as_deref() == Some(name) {
+            return Some(self);
+        }
+
+        self.children.iter().find_map(|child| child.find(name))
+    }
+
+    pub fn find_mut(&mut self, name: &str) -> Option<&mut SceneNode> {
+        if self.name.as_deref() == Some(name) {
+            return Some(self);
+        }
+
+        self.children.iter_mut().find_map(|child| child.find_mut(name))
+    }
+
+    pub fn world_transform(&self, name: &str) -> Option<Matrix> {
+        if self.name.as_deref() == Some(name) {
+            return Some(self.transform);
+        }
+
+        self.children.iter()
+            .find_map(|child| child.world_transform(name))
+            .map(|transform| self.transform * transform)
+    }
+
+    pub fn instance_count(&self) -> usize {
+        self.geometry.iter().count() + self.children.iter().map(|child| child.instance_count()).sum::<usize>()
+    }
+
+    pub fn surface(&self) -> MultipleSurfaces {
+        let mut surfaces = Vec::with_capacity(self.instance_count());
+        self.collect_surfaces((Matrix::IDENTITY, Matrix::IDENTITY), None, &mut surfaces);
+
+        MultipleSurfaces::new(surfaces)
+    }
+
+    fn collect_surfaces(&self, parent: (Matrix, Matrix), material: Option<&Material>, surfaces: &mut Vec<Box<dyn Surface + Send + Sync>>) {
+        let transform = (parent.0 * self.transform, self.inverse_transform * parent.1);
+        let material = self.material.as_ref().or(material);
+
+        if let Some(geometry) = &self.geometry {
+            match material {
+                Some(material) => surfaces.push(Box::new(TransformedSurface::from_matrices(transform.0, transform.1, MaterialOverride::new(material.clone(), geometry.clone())))),
+                _ => surfaces.push(Box::new(TransformedSurface::from_matrices(transform.0, transform.1, geometry.clone())))
+            }
+        }
+
+        for child in self.children.iter() {
+            child.collect_surfaces(transform, material, surfaces);
+        }
+    }
+}
+
+impl Default for SceneNode {
+    fn default() -> SceneNode {
+        SceneNode::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct SceneGraph {
+    pub root: SceneNode
+}
+
+impl SceneGraph {
+    pub fn new() -> SceneGraph {
+        SceneGraph { root: SceneNode::named("root") }
+    }
+
+    pub fn add(&mut self, node: SceneNode) {
+        self.root.children.push(node);
+    }
+
+    pub fn find(&self, name: &str) -> Option<&SceneNode> {
+        self.root.find(name)
+    }
+
+    pub fn find_mut(&mut self, name: &str) -> Option<&mut SceneNode> {
+        self.root.find_mut(name)
+    }
+
+    pub fn world_transform(&self, name: &str) -> Option<Matrix> {
+        self.root.world_transform(name)
+    }
+
+    pub fn surface(&self) -> MultipleSurfaces {
+        self.root.surface()
+    }
+}
+
+impl Default for SceneGraph {
+    fn default() -> SceneGraph {
+        SceneGraph::new()
+    }
+}
+
+pub struct Geometry {
+    node: SceneNode,
+    surface: MultipleSurfaces
+}
+
+impl Geometry {
+    pub fn new(node: SceneNode) -> Geometry {
+        Geometry { surface: node.surface(), node }
+    }
+
+    pub fn node(&self) -> &SceneNode {
+        &self.node
+    }
+}
+
+impl Surface for Geometry {
+    fn intersect(&self, ray: Ray) -> Option<SurfacePoint> {
+        self.surface.intersect(ray)
+    }
+
+    fn intersect_packet4(&self, packet: &RayPacket<4>) -> [Option<SurfacePoint>; 4] {
+        self.surface.intersect_packet4(packet)
+    }
+
+    fn intersect_packet8(&self, packet: &RayPacket<8>) -> [Option<SurfacePoint>; 8] {
+        self.surface.intersect_packet8(packet)
+    }
+
+    fn bounds(&self) -> Option<BoundingBox> {
+        self.surface.bounds()
+    }
+}
@@ -0,0 +1,670 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::math::color::Color;
+use crate::math::matrix::Matrix;
+use crate::math::point::Point;
+use crate::math::transformation::quaternion::Quaternion;
+use crate::math::transformation::transform::Transform;
+use crate::math::vector::Vector;
+use crate::renderer::environment::constant_environment::ConstantEnvironment;
+use crate::renderer::environment::gradient_environment::GradientEnvironment;
+use crate::renderer::environment::image_environment::ImageEnvironment;
+use crate::renderer::environment::sky_environment::SkyEnvironment;
+use crate::renderer::lens_shader::aperture::Aperture;
+use crate::renderer::lens_shader::cube_map_lens_shader::CubeMapLayout;
+use crate::renderer::lens_shader::field_of_view::{FieldOfView, Sensor};
+use crate::renderer::lens_shader::fisheye_lens_shader::FisheyeProjection;
+use crate::renderer::light::Light;
+use crate::renderer::pixel_shader::default_pixel_shader::DefaultPixelShader;
+use crate::renderer::pixel_shader::environment_light_pixel_shader::EnvironmentLightPixelShader;
+use crate::scene::camera::{Camera, CameraLens};
+use crate::scene::scene::Scene;
+use crate::scene::scene_error::SceneError;
+#[cfg(feature = "gltf")]
+use crate::scene::gltf_importer::{self, GltfImport};
+use crate::scene::scene_graph::{Geometry, SceneGraph, SceneNode, SharedSurface};
+use crate::scene::scene_pixel_shader::{SceneEnvironment, ScenePixelShader};
+use crate::scene::statement::Statement;
+use crate::surfaces::material::Material;
+use crate::surfaces::sphere::Sphere;
+use crate::textures::image_texture::{Filter, ImageTexture, WrapMode};
+use crate::textures::texture::Texture;
+
+pub fn load(statements: &[Statement], base_directory: &Path) -> Result<Scene, SceneError> {
+    let mut loader = SceneLoader { base_directory, materials: HashMap::new(), geometries: HashMap::new(), node_names: HashMap::new() };
+
+    let mut scene = Scene::new();
+    let mut lights = Vec::new();
+    let mut environment: Option<SceneEnvironment> = None;
+    let mut pixel_shader: Option<&Statement> = None;
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    let mut graph = SceneGraph::new();
+
+    for statement in statements {
+        if matches!(statement.name.as_str(), "image" | "camera" | "ray_shader" | "environment" | "pixel_shader") {
+            if let Some(line) = seen.insert(&statement.name, statement.line) {
+                return Err(statement.error(format!("'{}' is already defined on line {}", statement.name, line)));
+            }
+        }
+
+        match statement.name.as_str() {
+            "image" => loader.image(statement, &mut scene)?,
+            "camera" => scene.camera = loader.camera(statement)?,
+            "ray_shader" => scene.max_reflections = loader.ray_shader(statement)?,
+            "environment" => environment = Some(loader.environment(statement)?),
+            "pixel_shader" => pixel_shader = Some(statement),
+            "material" => loader.define_material(statement)?,
+            "geometry" => loader.define_geometry(statement)?,
+            "gltf" => {
+                let (node, camera, gltf_lights) = loader.gltf(statement, &mut scene.warnings)?;
+
+                if let Some(camera) = camera {
+                    if let Some(line) = seen.insert("camera", statement.line) {
+                        return Err(statement.error(format!("'camera' is already defined on line {}", line)));
+                    }
+
+                    scene.camera = camera;
+                }
+
+                graph.add(node);
+                lights.extend(gltf_lights);
+            }
+            _ => match loader.node(statement)? {
+                Some(node) => graph.add(node),
+                _ => return Err(statement.unknown("scene"))
+            }
+        }
+    }
+
+    let environment = environment.unwrap_or_else(|| Box::new(GradientEnvironment::sky()));
+
+    scene.pixel_shader = match pixel_shader {
+        Some(statement) => loader.pixel_shader(statement, environment)?,
+        _ => ScenePixelShader::Default(DefaultPixelShader::new(environment))
+    };
+
+    if !lights.is_empty() {
+        scene.pixel_shader = match scene.pixel_shader {
+            ScenePixelShader::EnvironmentLight(pixel_shader) => ScenePixelShader::EnvironmentLight(pixel_shader.with_lights(lights)),
+            pixel_shader => {
+                scene.warnings.push(format!("{} light(s) are ignored, only the environment_light pixel shader renders lights", lights.len()));
+                pixel_shader
+            }
+        };
+    }
+
+    scene.graph = graph;
+    Ok(scene)
+}
+
+#[cfg(feature = "gltf")]
+pub fn is_gltf(path: &Path) -> bool {
+    matches!(path.extension().and_then(|extension| extension.to_str()).map(|extension| extension.to_ascii_lowercase()).as_deref(), Some("gltf" | "glb"))
+}
+
+#[cfg(feature = "gltf")]
+pub fn load_gltf(path: &Path) -> Result<Scene, SceneError> {
+    let import = gltf_importer::import(path)?;
+    let mut scene = Scene::new();
+
+    scene.warnings = gltf_warnings(path, &import);
+
+    if let Some(camera) = import.cameras.first() {
+        scene.camera = camera.clone();
+    }
+
+    let environment: SceneEnvironment = Box::new(GradientEnvironment::sky());
+    scene.pixel_shader = ScenePixelShader::EnvironmentLight(EnvironmentLightPixelShader::new(environment, 16).with_lights(import.lights));
+
+    for node in import.nodes {
+        scene.graph.add(node);
+    }
+
+    Ok(scene)
+}
+
+#[cfg(feature = "gltf")]
+fn gltf_warnings(path: &Path, import: &GltfImport) -> Vec<String> {
+    import.skipped_extensions.iter()
+        .map(|extension| format!("{}: skipped unsupported extension '{}'", path.display(), extension))
+        .chain(import.warnings.iter().map(|warning| format!("{}: {}", path.display(), warning)))
+        .collect()
+}
+
+struct SceneLoader<'a> {
+    base_directory: &'a Path,
+    materials: HashMap<String, Material>,
+    geometries: HashMap<String, SharedSurface>,
+    node_names: HashMap<String, usize>
+}
+
+impl SceneLoader<'_> {
+    fn path(&self, path: &str) -> PathBuf {
+        self.base_directory.join(path)
+    }
+
+    fn image(&self, statement: &Statement, scene: &mut Scene) -> Result<(), SceneError> {
+        statement.expect_arguments(0)?;
+
+        for child in statement.children() {
+            match child.name.as_str() {
+                "width" => scene.width = positive_count(child)?,
+                "height" => scene.height = positive_count(child)?,
+                "samples" => scene.samples_per_pixel = positive_count(child)?,
+                "fill" => scene.fill_color = child.color()?,
+                "shutter" => {
+                    child.expect_arguments(2)?;
+                    let (open, close) = (child.number(0)?, child.number(1)?);
+
+                    if close < open {
+                        return Err(child.error("shutter closes before it opens"));
+                    }
+
+                    scene.shutter = (open, close);
+                }
+                _ => return Err(child.unknown("image"))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn camera(&self, statement: &Statement) -> Result<Camera, SceneError> {
+        statement.expect_arguments(1)?;
+        let kind = statement.word(0)?;
+
+        let mut lens = CameraLens::named(kind)
+            .ok_or_else(|| statement.error(format!("unknown camera kind '{}'", kind)))?;
+        let (mut camera, mut look_at) = (Camera::default(), None);
+
+        for child in statement.children() {
+            if let Some(transform) = transform(child)? {
+                camera = camera.transformed(transform);
+                continue;
+            }
+
+            match (&mut lens, child.name.as_str()) {
+                (CameraLens::Perspective(field_of_view) | CameraLens::ThinLens { field_of_view, .. }, "vertical_fov") => *field_of_view = FieldOfView::Vertical(angle(child)?),
+                (CameraLens::Perspective(field_of_view) | CameraLens::ThinLens { field_of_view, .. }, "horizontal_fov") => *field_of_view = FieldOfView::Horizontal(angle(child)?),
+                (CameraLens::Perspective(field_of_view) | CameraLens::ThinLens { field_of_view, .. }, "focal_length") => *field_of_view = focal_length(child)?,
+                (CameraLens::ThinLens { aperture_radius, .. }, "aperture_radius") => *aperture_radius = child.single_number()?.max(0.0),
+                (CameraLens::ThinLens { focus_distance, .. }, "focus_distance") => *focus_distance = positive_number(child)?,
+                (CameraLens::ThinLens { aperture, .. }, "blades") => *aperture = blades(child)?,
+                (CameraLens::Fisheye { projection, .. }, "projection") => *projection = fisheye_projection(child)?,
+                (CameraLens::Fisheye { field_of_view, .. }, "fov") => *field_of_view = angle(child)?,
+                (CameraLens::Orthographic { height } | CameraLens::Panorama { height, .. }, "height") => *height = positive_number(child)?,
+                (CameraLens::Panorama { radius, .. }, "radius") => *radius = positive_number(child)?,
+                (CameraLens::CubeMap(layout), "layout") => *layout = cube_map_layout(child)?,
+                (_, "look_at") => look_at = Some(camera_look_at(child)?),
+                _ => return Err(child.unknown(&format!("{} camera", kind)))
+            }
+        }
+
+        Ok(Camera { lens, look_at, ..camera })
+    }
+
+    #[cfg(feature = "gltf")]
+    fn gltf(&self, statement: &Statement, warnings: &mut Vec<String>) -> Result<(SceneNode, Option<Camera>, Vec<Light>), SceneError> {
+        statement.expect_arguments(1)?;
+        let path = self.path(statement.text(0)?);
+
+        let import = gltf_importer::import(&path)
+            .map_err(|error| statement.error(format!("cannot import '{}': {}", path.display(), error)))?;
+        warnings.extend(gltf_warnings(&path, &import));
+
+        let mut node = SceneNode::new();
+        let (mut camera, mut light_intensity) = (None, 1.0);
+
+        for child in statement.children() {
+            if let Some(transform) = transform(child)? {
+                node = node.transformed(transform);
+                continue;
+            }
+
+            match child.name.as_str() {
+                "material" => node.material = Some(self.material(child)?),
+                "camera" => {
+                    let index = child.single_count()?;
+
+                    camera = Some(import.cameras.get(index).cloned().ok_or_else(|| {
+                        child.error(format!("'{}' has {} camera(s), there is no camera {}", path.display(), import.cameras.len(), index))
+                    })?);
+                }
+                "light_intensity" => light_intensity = child.single_number()?.max(0.0),
+                _ => return Err(child.unknown("gltf"))
+            }
+        }
+
+        let camera = camera.map(|camera| Camera { transform: node.transform() * camera.transform, ..camera });
+        let lights = import.lights.into_iter()
+            .map(|light| light.scaled(light_intensity).transformed(node.transform()))
+            .collect();
+
+        node.children = import.nodes;
+        Ok((node, camera, lights))
+    }
+
+    #[cfg(not(feature = "gltf"))]
+    fn gltf(&self, statement: &Statement, _warnings: &mut Vec<String>) -> Result<(SceneNode, Option<Camera>, Vec<Light>), SceneError> {
+        Err(statement.error("this build does not support glTF, enable the 'gltf' feature"))
+    }
+
+    fn ray_shader(&self, statement: &Statement) -> Result<usize, SceneError> {
+        statement.expect_arguments(0)?;
+        let mut max_reflections = 5;
+
+        for child in statement.children() {
+            match child.name.as_str() {
+                "max_reflections" => max_reflections = child.single_count()?,
+                _ => return Err(child.unknown("ray_shader"))
+            }
+        }
+
+        Ok(max_reflections)
+    }
+
+    fn environment(&self, statement: &Statement) -> Result<SceneEnvironment, SceneError> {
+        let kind = statement.word(0)?;
+        statement.expect_arguments(if kind == "image" { 2 } else { 1 })?;
+
+        match kind {
+            "constant" => {
+                let mut color = Color::BLACK;
+
+                for child in statement.children() {
+                    match child.name.as_str() {
+                        "color" => color = child.color()?,
+                        _ => return Err(child.unknown("constant environment"))
+                    }
+                }
+
+                Ok(Box::new(ConstantEnvironment::new(color)))
+            }
+            "gradient" => {
+                let mut gradient = GradientEnvironment::sky();
+
+                for child in statement.children() {
+                    match child.name.as_str() {
+                        "zenith" => gradient.zenith = child.color()?,
+                        "horizon" => gradient.horizon = child.color()?,
+                        "ground" => gradient.ground = child.color()?,
+                        _ => return Err(child.unknown("gradient environment"))
+                    }
+                }
+
+                Ok(Box::new(gradient))
+            }
+            "sky" => {
+                let (mut sun, mut turbidity, mut albedo, mut intensity) = (Vector::new(0.3, 0.8, 0.5), 3.0, Color::solid(0.3, 0.3, 0.3), 1.0);
+
+                for child in statement.children() {
+                    match child.name.as_str() {
+                        "sun" => sun = child.vector()?,
+                        "turbidity" => turbidity = positive_number(child)?,
+                        "albedo" => albedo = child.color()?,
+                        "intensity" => intensity = child.single_number()?,
+                        _ => return Err(child.unknown("sky environment"))
+                    }
+                }
+
+                if sun.is_approximately_zero() {
+                    return Err(statement.error("sun direction must not be zero"));
+                }
+
+                Ok(Box::new(SkyEnvironment::new(sun, turbidity, albedo, intensity)))
+            }
+            "image" => {
+                let path = self.path(statement.text(1)?);
+                let mut intensity = 1.0;
+
+                for child in statement.children() {
+                    match child.name.as_str() {
+                        "intensity" => intensity = child.single_number()?,
+                        _ => return Err(child.unknown("image environment"))
+                    }
+                }
+
+                let environment = ImageEnvironment::from_file(&path, intensity)
+                    .map_err(|error| statement.error(format!("cannot read '{}': {}", path.display(), error)))?;
+
+                Ok(Box::new(environment))
+            }
+            _ => Err(statement.error(format!("unknown environment kind '{}'", kind)))
+        }
+    }
+
+    fn pixel_shader(&self, statement: &Statement, environment: SceneEnvironment) -> Result<ScenePixelShader, SceneError> {
+        statement.expect_arguments(1)?;
+
+        match statement.word(0)? {
+            "default" => {
+                if let Some(child) = statement.children().first() {
+                    return Err(child.unknown("default pixel shader"));
+                }
+
+                Ok(ScenePixelShader::Default(DefaultPixelShader::new(environment)))
+            }
+            "environment_light" => {
+                let mut samples = 16;
+
+                for child in statement.children() {
+                    match child.name.as_str() {
+                        "samples" => samples = positive_count(child)?,
+                        _ => return Err(child.unknown("environment light pixel shader"))
+                    }
+                }
+
+                Ok(ScenePixelShader::EnvironmentLight(EnvironmentLightPixelShader::new(environment, samples)))
+            }
+            kind => Err(statement.error(format!("unknown pixel shader '{}'", kind)))
+        }
+    }
+
+    fn define_material(&mut self, statement: &Statement) -> Result<(), SceneError> {
+        statement.expect_arguments(1)?;
+        let name = statement.word(0)?;
+
+        if self.materials.contains_key(name) {
+            return Err(statement.error(format!("material '{}' is already defined", name)));
+        }
+
+        let material = self.material_block(statement)?;
+        self.materials.insert(name.to_string(), material);
+        Ok(())
+    }
+
+    fn material(&self, statement: &Statement) -> Result<Material, SceneError> {
+        if statement.block.is_some() {
+            statement.expect_arguments(0)?;
+            return self.material_block(statement);
+        }
+
+        statement.expect_arguments(1)?;
+        let name = statement.word(0)?;
+
+        self.materials.get(name)
+            .cloned()
+            .ok_or_else(|| statement.error(format!("unknown material '{}'", name)))
+    }
+
+    fn material_block(&self, statement: &Statement) -> Result<Material, SceneError> {
+        let mut material = Material::new(Color::WHITE);
+
+        for child in statement.children() {
+            match child.name.as_str() {
+                "diffuse" => material.diffuse = child.color()?,
+                "texture" => material.diffuse_texture = Some(self.texture(child)?),
+                "emission_texture" => material.emission_texture = Some(self.texture(child)?),
+                "metallic_roughness_texture" => material.metallic_roughness_texture = Some(self.texture(child)?),
+                "normal_map" => material.normal_map = Some(self.texture(child)?),
+                "bump_map" => material.bump_map = Some(self.texture(child)?),
+                "bump_strength" => material.bump_strength = child.single_number()?,
+                "emission" => material.emission = child.color()?,
+                "metallic" => material.metallic = unit_number(child)?,
+                "roughness" => material.roughness = unit_number(child)?,
+                _ => return Err(child.unknown("material"))
+            }
+        }
+
+        Ok(material)
+    }
+
+    fn texture(&self, statement: &Statement) -> Result<Arc<dyn Texture + Send + Sync>, SceneError> {
+        statement.expect_arguments(1)?;
+        let path = self.path(statement.text(0)?);
+
+        let texture = ImageTexture::from_file(&path, WrapMode::Repeat, Filter::Bilinear)
+            .map_err(|error| statement.error(format!("cannot read '{}': {}", path.display(), error)))?;
+
+        Ok(Arc::new(texture))
+    }
+
+    fn define_geometry(&mut self, statement: &Statement) -> Result<(), SceneError> {
+        statement.expect_arguments(1)?;
+        let name = statement.word(0)?;
+
+        if self.geometries.contains_key(name) {
+            return Err(statement.error(format!("geometry '{}' is already defined", name)));
+        }
+
+        let mut geometry = SceneNode::new();
+
+        for child in statement.children() {
+            match self.node(child)? {
+                Some(node) => geometry.children.push(node),
+                _ => return Err(child.unknown("geometry"))
+            }
+        }
+
+        self.geometries.insert(name.to_string(), Arc::new(Geometry::new(geometry)));
+        Ok(())
+    }
+
+    fn node(&mut self, statement: &Statement) -> Result<Option<SceneNode>, SceneError> {
+        let mut node = match statement.name.as_str() {
+            "sphere" => {
+                statement.expect_arguments(0)?;
+                let (mut radius, mut material) = (1.0, Material::new(Color::WHITE));
+
+                for child in statement.children() {
+                    match child.name.as_str() {
+                        "radius" => radius = positive_number(child)?,
+                        "material" => material = self.material(child)?,
+                        _ if is_transform(child) => (),
+                        _ => return Err(child.unknown("sphere"))
+                    }
+                }
+
+                SceneNode::instance(Arc::new(Sphere::new(radius, material)))
+            }
+            "instance" => {
+                statement.expect_arguments(1)?;
+                let name = statement.word(0)?;
+
+                let geometry = self.geometries.get(name)
+                    .ok_or_else(|| statement.error(format!("unknown geometry '{}'", name)))?;
+
+                SceneNode::instance(geometry.clone())
+            }
+            "group" => {
+                statement.expect_arguments(0)?;
+                SceneNode::new()
+            }
+            "node" => {
+                statement.expect_arguments(1)?;
+                let name = statement.word(0)?;
+
+                if let Some(line) = self.node_names.insert(name.to_string(), statement.line) {
+                    return Err(statement.error(format!("node '{}' is already defined on line {}", name, line)));
+                }
+
+                SceneNode::named(name)
+            }
+            _ => return Ok(None)
+        };
+
+        for child in statement.children() {
+            if let Some(transform) = transform(child)? {
+                node = node.transformed(transform);
+                continue;
+            }
+
+            match (statement.name.as_str(), child.name.as_str()) {
+                ("sphere", _) => (),
+                (_, "material") => node.material = Some(self.material(child)?),
+                ("group" | "node", _) => match self.node(child)? {
+                    Some(child_node) => node.children.push(child_node),
+                    _ => return Err(child.unknown(&statement.name))
+                },
+                _ => return Err(child.unknown(&statement.name))
+            }
+        }
+
+        Ok(Some(node))
+    }
+}
+
+fn is_transform(statement: &Statement) -> bool {
+    matches!(statement.name.as_str(), "translate" | "scale" | "rotate" | "matrix")
+}
+
+fn transform(statement: &Statement) -> Result<Option<Transform>, SceneError> {
+    let transform = match statement.name.as_str() {
+        "translate" => Transform::translation(statement.vector()?),
+        "scale" => match statement.arguments.len() {
+            1 => Transform::scale(Vector::ONE * statement.number(0)?),
+            _ => Transform::scale(statement.vector()?)
+        },
+        "rotate" => {
+            statement.expect_arguments(2)?;
+            let radians = statement.number(1)?.to_radians();
+
+            let axis = match statement.word(0)? {
+                "x" => Vector::RIGHT,
+                "y" => Vector::UP,
+                "z" => Vector::FORWARD,
+                axis => return Err(statement.error(format!("unknown rotation axis '{}', expected x, y or z", axis)))
+            };
+
+            Transform::orientation(Quaternion::from_axis_angle(axis, radians))
+        }
+        "matrix" => {
+            statement.expect_arguments(12)?;
+            let number = |index| statement.number(index);
+
+            let matrix = Matrix::from_rows([
+                [number(0)?, number(1)?, number(2)?, number(3)?],
+                [number(4)?, number(5)?, number(6)?, number(7)?],
+                [number(8)?, number(9)?, number(10)?, number(11)?],
+                [0.0, 0.0, 0.0, 1.0]
+            ]);
+
+            Transform::from_matrix(matrix).ok_or_else(|| statement.error("'matrix' must be invertible"))?
+        }
+        _ => return Ok(None)
+    };
+
+    Ok(Some(transform))
+}
+
+fn camera_look_at(statement: &Statement) -> Result<(Point, Point, Vector), SceneError> {
+    let number = |index| statement.number(index);
+
+    let (eye, target, up) = match statement.arguments.len() {
+        6 | 9 => (
+            Point::new(number(0)?, number(1)?, number(2)?),
+            Point::new(number(3)?, number(4)?, number(5)?),
+            if statement.arguments.len() == 9 { Vector::new(number(6)?, number(7)?, number(8)?) } else { Vector::UP }
+        ),
+        count => return Err(statement.error(format!("'look_at' expects an eye, a target and an optional up vector, found {} numbers", count)))
+    };
+
+    if Vector::cross(target - eye, up).is_approximately_zero() {
+        return Err(statement.error("'look_at' needs distinct eye and target points and an up vector that is not parallel to the view direction"));
+    }
+
+    Ok((eye, target, up))
+}
+
+fn angle(statement: &Statement) -> Result<f64, SceneError> {
+    let degrees = statement.single_number()?;
+
+    if degrees <= 0.0 || degrees > 360.0 {
+        return Err(statement.error(format!("'{}' must be between 0 and 360 degrees", statement.name)));
+    }
+
+    Ok(degrees.to_radians())
+}
+
+fn focal_length(statement: &Statement) -> Result<FieldOfView, SceneError> {
+    let focal_length = statement.number(0)?;
+
+    let sensor = match statement.arguments.len() {
+        1 => Sensor::FULL_FRAME,
+        2 => match statement.word(1)? {
+            "full_frame" => Sensor::FULL_FRAME,
+            "aps_c" => Sensor::APS_C,
+            "micro_four_thirds" => Sensor::MICRO_FOUR_THIRDS,
+            sensor => return Err(statement.error(format!("unknown sensor '{}'", sensor)))
+        },
+        3 => Sensor::new(statement.number(1)?, statement.number(2)?),
+        _ => return Err(statement.error("'focal_length' expects a length in millimeters and an optional sensor"))
+    };
+
+    if focal_length <= 0.0 || sensor.width <= 0.0 || sensor.height <= 0.0 {
+        return Err(statement.error("'focal_length' and the sensor size must be positive"));
+    }
+
+    Ok(FieldOfView::FocalLength { focal_length, sensor })
+}
+
+fn blades(statement: &Statement) -> Result<Aperture, SceneError> {
+    let blades = statement.count(0)?;
+
+    let rotation = match statement.arguments.len() {
+        1 => 0.0,
+        2 => statement.number(1)?.to_radians(),
+        _ => return Err(statement.error("'blades' expects a blade count and an optional rotation"))
+    };
+
+    if blades < 3 {
+        return Err(statement.error("an aperture needs at least 3 blades"));
+    }
+
+    Ok(Aperture::Polygonal { blades, rotation })
+}
+
+fn fisheye_projection(statement: &Statement) -> Result<FisheyeProjection, SceneError> {
+    statement.expect_arguments(1)?;
+
+    match statement.word(0)? {
+        "equidistant" => Ok(FisheyeProjection::Equidistant),
+        "equisolid" => Ok(FisheyeProjection::Equisolid),
+        "orthographic" => Ok(FisheyeProjection::Orthographic),
+        "stereographic" => Ok(FisheyeProjection::Stereographic),
+        projection => Err(statement.error(format!("unknown fisheye projection '{}'", projection)))
+    }
+}
+
+fn cube_map_layout(statement: &Statement) -> Result<CubeMapLayout, SceneError> {
+    statement.expect_arguments(1)?;
+
+    match statement.word(0)? {
+        "horizontal_strip" => Ok(CubeMapLayout::HorizontalStrip),
+        "vertical_strip" => Ok(CubeMapLayout::VerticalStrip),
+        "horizontal_cross" => Ok(CubeMapLayout::HorizontalCross),
+        "vertical_cross" => Ok(CubeMapLayout::VerticalCross),
+        layout => Err(statement.error(format!("unknown cube map layout '{}'", layout)))
+    }
+}
+
+fn positive_number(statement: &Statement) -> Result<f64, SceneError> {
+    let number = statement.single_number()?;
+
+    if number <= 0.0 {
+        return Err(statement.error(format!("'{}' must be positive", statement.name)));
+    }
+
+    Ok(number)
+}
+
+fn unit_number(statement: &Statement) -> Result<f64, SceneError> {
+    let number = statement.single_number()?;
+
+    if !(0.0..=1.0).contains(&number) {
+        return Err(statement.error(format!("'{}' must be between 0 and 1", statement.name)));
+    }
+
+    Ok(number)
+}
+
+fn positive_count(statement: &Statement) -> Result<usize, SceneError> {
+    let count = statement.single_count()?;
+
+    if count == 0 {
+        return Err(statement.error(format!("'{}' must be at least 1", statement.name)));
+    }
+
+    Ok(count)
+}
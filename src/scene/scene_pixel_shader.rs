@@ -0,0 +1,24 @@
+use std::collections::VecDeque;
+use crate::math::color::Color;
+use crate::math::ray::Ray;
+use crate::renderer::pixel_shader::default_pixel_shader::DefaultPixelShader;
+use crate::renderer::pixel_shader::environment_light_pixel_shader::EnvironmentLightPixelShader;
+use crate::renderer::pixel_shader::PixelShader;
+use crate::scene::exportable::ExportableEnvironment;
+use crate::surfaces::surface::{Surface, SurfacePoint};
+
+pub type SceneEnvironment = Box<dyn ExportableEnvironment>;
+
+pub enum ScenePixelShader {
+    Default(DefaultPixelShader<SceneEnvironment>),
+    EnvironmentLight(EnvironmentLightPixelShader<SceneEnvironment>)
+}
+
+impl PixelShader for ScenePixelShader {
+    fn final_color<S: Surface>(&self, stack: &VecDeque<SurfacePoint>, escaped_ray: Option<Ray>, surface: &S) -> Color {
+        match self {
+            ScenePixelShader::Default(pixel_shader) => pixel_shader.final_color(stack, escaped_ray, surface),
+            ScenePixelShader::EnvironmentLight(pixel_shader) => pixel_shader.final_color(stack, escaped_ray, surface)
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::vec::IntoIter;
+use crate::math::color::Color;
+use crate::math::point::Point;
+use crate::math::vector::Vector;
+use crate::scene::scene_error::SceneError;
+use crate::scene::tokenizer::{tokenize, Token, TokenKind};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Number(f64),
+    Word(String),
+    Text(String)
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{}", number),
+            Value::Word(word) => write!(f, "{}", word),
+            Value::Text(text) => write!(f, "\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Statement {
+    pub name: String,
+    pub arguments: Vec<Value>,
+    pub block: Option<Vec<Statement>>,
+    pub line: usize
+}
+
+impl Display for Statement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        self.format(&mut output, 0);
+
+        write!(f, "{}", output.trim_end())
+    }
+}
+
+impl Statement {
+    pub fn new<N: Into<String>>(name: N, arguments: Vec<Value>) -> Statement {
+        Statement { name: name.into(), arguments, block: None, line: 0 }
+    }
+
+    pub fn numbers<N: Into<String>>(name: N, numbers: &[f64]) -> Statement {
+        Statement::new(name, numbers.iter().map(|number| Value::Number(*number)).collect())
+    }
+
+    pub fn with_block(self, block: Vec<Statement>) -> Statement {
+        Statement { block: Some(block), ..self }
+    }
+
+    pub fn format_all(statements: &[Statement]) -> String {
+        let mut output = String::new();
+
+        for statement in statements {
+            statement.format(&mut output, 0);
+        }
+
+        output
+    }
+
+    fn format(&self, output: &mut String, depth: usize) {
+        output.push_str(&"    ".repeat(depth));
+        output.push_str(&self.name);
+
+        for argument in self.arguments.iter() {
+            output.push_str(&format!(" {}", argument));
+        }
+
+        if let Some(block) = &self.block {
+            output.push_str(" {\n");
+
+            for statement in block {
+                statement.format(output, depth + 1);
+            }
+
+            output.push_str(&"    ".repeat(depth));
+            output.push('}');
+        }
+
+        output.push('\n');
+    }
+
+    pub fn parse_all(source: &str) -> Result<Vec<Statement>, SceneError> {
+        let mut tokens = tokenize(source)?.into_iter().peekable();
+        let statements = Statement::parse_block(&mut tokens, None)?;
+
+        match tokens.next() {
+            Some(token) => Err(SceneError::at(token.line, "unexpected '}'")),
+            _ => Ok(statements)
+        }
+    }
+
+    fn parse_block(tokens: &mut Peekable<IntoIter<Token>>, opened_at: Option<usize>) -> Result<Vec<Statement>, SceneError> {
+        let mut statements = Vec::new();
+
+        loop {
+            let token = match tokens.next() {
+                Some(token) => token,
+                _ => return match opened_at {
+                    Some(line) => Err(SceneError::at(line, "block is never closed")),
+                    _ => Ok(statements)
+                }
+            };
+
+            let (name, line) = match token.kind {
+                TokenKind::Separator => continue,
+                TokenKind::CloseBrace if opened_at.is_some() => return Ok(statements),
+                TokenKind::CloseBrace => return Err(SceneError::at(token.line, "unexpected '}'")),
+                TokenKind::Word(name) => (name, token.line),
+                _ => return Err(SceneError::at(token.line, "expected a statement name"))
+            };
+
+            let mut arguments = Vec::new();
+            let mut block = None;
+
+            while let Some(next) = tokens.peek() {
+                match &next.kind {
+                    TokenKind::Number(number) => arguments.push(Value::Number(*number)),
+                    TokenKind::Word(word) => arguments.push(Value::Word(word.clone())),
+                    TokenKind::Text(text) => arguments.push(Value::Text(text.clone())),
+                    TokenKind::OpenBrace => {
+                        let opened_at = next.line;
+                        tokens.next();
+                        block = Some(Statement::parse_block(tokens, Some(opened_at))?);
+                        break;
+                    }
+                    _ => break
+                }
+
+                tokens.next();
+            }
+
+            statements.push(Statement { name, arguments, block, line });
+        }
+    }
+
+    pub fn error<M: Into<String>>(&self, message: M) -> SceneError {
+        SceneError::at(self.line, message)
+    }
+
+    pub fn children(&self) -> &[Statement] {
+        self.block.as_deref().unwrap_or(&[])
+    }
+
+    pub fn expect_arguments(&self, count: usize) -> Result<(), SceneError> {
+        if self.arguments.len() != count {
+            return Err(self.error(format!("'{}' expects {} argument(s), found {}", self.name, count, self.arguments.len())));
+        }
+
+        Ok(())
+    }
+
+    pub fn number(&self, index: usize) -> Result<f64, SceneError> {
+        match self.arguments.get(index) {
+            Some(Value::Number(number)) => Ok(*number),
+            Some(value) => Err(self.error(format!("'{}' expects a number, found {}", self.name, value))),
+            _ => Err(self.error(format!("'{}' is missing argument {}", self.name, index + 1)))
+        }
+    }
+
+    pub fn count(&self, index: usize) -> Result<usize, SceneError> {
+        let number = self.number(index)?;
+
+        if number < 0.0 || number.fract() != 0.0 {
+            return Err(self.error(format!("'{}' expects a whole number, found {}", self.name, number)));
+        }
+
+        Ok(number as usize)
+    }
+
+    pub fn word(&self, index: usize) -> Result<&str, SceneError> {
+        match self.arguments.get(index) {
+            Some(Value::Word(word)) => Ok(word),
+            Some(value) => Err(self.error(format!("'{}' expects a name, found {}", self.name, value))),
+            _ => Err(self.error(format!("'{}' is missing argument {}", self.name, index + 1)))
+        }
+    }
+
+    pub fn text(&self, index: usize) -> Result<&str, SceneError> {
+        match self.arguments.get(index) {
+            Some(Value::Text(text)) => Ok(text),
+            Some(value) => Err(self.error(format!("'{}' expects a quoted string, found {}", self.name, value))),
+            _ => Err(self.error(format!("'{}' is missing argument {}", self.name, index + 1)))
+        }
+    }
+
+    pub fn single_number(&self) -> Result<f64, SceneError> {
+        self.expect_arguments(1)?;
+        self.number(0)
+    }
+
+    pub fn single_count(&self) -> Result<usize, SceneError> {
+        self.expect_arguments(1)?;
+        self.count(0)
+    }
+
+    pub fn vector(&self) -> Result<Vector, SceneError> {
+        self.expect_arguments(3)?;
+        Ok(Vector::new(self.number(0)?, self.number(1)?, self.number(2)?))
+    }
+
+    pub fn point(&self) -> Result<Point, SceneError> {
+        self.expect_arguments(3)?;
+        Ok(Point::new(self.number(0)?, self.number(1)?, self.number(2)?))
+    }
+
+    pub fn color(&self) -> Result<Color, SceneError> {
+        match self.arguments.len() {
+            1 => {
+                let value = self.number(0)?;
+                Ok(Color::solid(value, value, value))
+            }
+            3 => Ok(Color::solid(self.number(0)?, self.number(1)?, self.number(2)?)),
+            4 => Ok(Color::new(self.number(0)?, self.number(1)?, self.number(2)?, self.number(3)?)),
+            count => Err(self.error(format!("'{}' expects 1, 3 or 4 color components, found {}", self.name, count)))
+        }
+    }
+
+    pub fn unknown(&self, context: &str) -> SceneError {
+        self.error(format!("unknown statement '{}' in {}", self.name, context))
+    }
+}
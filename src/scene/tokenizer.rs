@@ -0,0 +1,86 @@
+use crate::scene::scene_error::SceneError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TokenKind {
+    Word(String),
+    Number(f64),
+    Text(String),
+    OpenBrace,
+    CloseBrace,
+    Separator
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub line: usize
+}
+
+pub fn tokenize(source: &str) -> Result<Vec<Token>, SceneError> {
+    let mut tokens = Vec::new();
+    let mut characters = source.chars().peekable();
+    let mut line = 1;
+
+    while let Some(character) = characters.next() {
+        let token_line = line;
+        let kind = match character {
+            '\n' => {
+                line += 1;
+                TokenKind::Separator
+            }
+            ';' => TokenKind::Separator,
+            '{' => TokenKind::OpenBrace,
+            '}' => TokenKind::CloseBrace,
+            '#' => {
+                while characters.next_if(|next| *next != '\n').is_some() {}
+                continue;
+            }
+            '"' => {
+                let mut text = String::new();
+
+                loop {
+                    match characters.next() {
+                        Some('"') => break,
+                        Some('\\') => match characters.next() {
+                            Some('n') => text.push('\n'),
+                            Some(escaped @ ('"' | '\\')) => text.push(escaped),
+                            _ => return Err(SceneError::at(line, "invalid escape sequence in string"))
+                        },
+                        Some('\n') => return Err(SceneError::at(token_line, "unterminated string")),
+                        Some(next) => text.push(next),
+                        None => return Err(SceneError::at(token_line, "unterminated string"))
+                    }
+                }
+
+                TokenKind::Text(text)
+            }
+            character if character.is_whitespace() => continue,
+            character if character.is_ascii_digit() || matches!(character, '-' | '+' | '.') => {
+                let mut number = String::from(character);
+
+                while let Some(next) = characters.next_if(|next| next.is_ascii_alphanumeric() || matches!(next, '.' | '-' | '+')) {
+                    number.push(next);
+                }
+
+                match number.parse::<f64>() {
+                    Ok(value) => TokenKind::Number(value),
+                    _ => return Err(SceneError::at(line, format!("invalid number '{}'", number)))
+                }
+            }
+            character if character.is_alphabetic() || character == '_' => {
+                let mut word = String::from(character);
+
+                while let Some(next) = characters.next_if(|next| next.is_alphanumeric() || *next == '_') {
+                    word.push(next);
+                }
+
+                TokenKind::Word(word)
+            }
+            character => return Err(SceneError::at(line, format!("unexpected character '{}'", character)))
+        };
+
+        tokens.push(Token { kind, line: token_line });
+    }
+
+    Ok(tokens)
+}
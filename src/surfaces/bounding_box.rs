@@ -0,0 +1,90 @@
+use crate::math::float::Float;
+use crate::math::matrix::Matrix;
+use crate::math::point::Point;
+use crate::math::ray::Ray;
+use crate::math::simd::{Lanes, VectorPacket};
+use crate::math::vector::Vector;
+
+#[derive(Copy, Clone)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point
+}
+
+impl BoundingBox {
+    pub const EMPTY: BoundingBox = BoundingBox {
+        min: Point { x: f64::INFINITY, y: f64::INFINITY, z: f64::INFINITY },
+        max: Point { x: f64::NEG_INFINITY, y: f64::NEG_INFINITY, z: f64::NEG_INFINITY }
+    };
+
+    pub fn new(min: Point, max: Point) -> BoundingBox {
+        BoundingBox { min, max }
+    }
+
+    pub fn from_points(points: &[Point]) -> BoundingBox {
+        points.iter().fold(BoundingBox::EMPTY, |bounds, point| BoundingBox::union(bounds, BoundingBox::new(*point, *point)))
+    }
+
+    pub fn union(a: BoundingBox, b: BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min: Point::new(a.min.x.min(b.min.x), a.min.y.min(b.min.y), a.min.z.min(b.min.z)),
+            max: Point::new(a.max.x.max(b.max.x), a.max.y.max(b.max.y), a.max.z.max(b.max.z))
+        }
+    }
+
+    pub fn transformed(&self, matrix: Matrix) -> BoundingBox {
+        if self.min.x > self.max.x {
+            return *self;
+        }
+
+        let corners = [0, 1, 2, 3, 4, 5, 6, 7].map(|corner| matrix * Point::new(
+            if corner & 1 == 0 { self.min.x } else { self.max.x },
+            if corner & 2 == 0 { self.min.y } else { self.max.y },
+            if corner & 4 == 0 { self.min.z } else { self.max.z }
+        ));
+
+        BoundingBox::from_points(&corners)
+    }
+
+    pub fn centroid(&self) -> Point {
+        self.min + (self.max - self.min) * 0.5
+    }
+
+    pub fn extent(&self) -> Vector {
+        self.max - self.min
+    }
+
+    pub fn intersect(&self, ray: Ray, inverse_direction: Vector, t_max: f64) -> Option<f64> {
+        let slab = |min: f64, max: f64, origin: f64, inverse: f64| {
+            let (t0, t1) = ((min - origin) * inverse, (max - origin) * inverse);
+            if t0 <= t1 { (t0, t1) } else { (t1, t0) }
+        };
+
+        let (x0, x1) = slab(self.min.x, self.max.x, ray.origin.x, inverse_direction.x);
+        let (y0, y1) = slab(self.min.y, self.max.y, ray.origin.y, inverse_direction.y);
+        let (z0, z1) = slab(self.min.z, self.max.z, ray.origin.z, inverse_direction.z);
+
+        let near = x0.max(y0).max(z0).max(ray.t_min);
+        let far = (x1.min(y1).min(z1) * (1.0 + 2.0 * f64::error_bound(3))).min(t_max);
+
+        if near <= far { Some(near) } else { None }
+    }
+
+    #[inline(always)]
+    pub fn intersect_packet<const N: usize>(&self, origins: VectorPacket<N>, inverse_directions: VectorPacket<N>, t_min: Lanes<N>, t_max: Lanes<N>) -> [bool; N] {
+        let slab = |min: f64, max: f64, origin: Lanes<N>, inverse: Lanes<N>| {
+            let (t0, t1) = ((Lanes::splat(min) - origin) * inverse, (Lanes::splat(max) - origin) * inverse);
+            let ordered = t0.le(t1);
+            (Lanes::select(ordered, t0, t1), Lanes::select(ordered, t1, t0))
+        };
+
+        let (x0, x1) = slab(self.min.x, self.max.x, origins.x, inverse_directions.x);
+        let (y0, y1) = slab(self.min.y, self.max.y, origins.y, inverse_directions.y);
+        let (z0, z1) = slab(self.min.z, self.max.z, origins.z, inverse_directions.z);
+
+        let near = x0.max(y0).max(z0).max(t_min);
+        let far = (x1.min(y1).min(z1) * Lanes::splat(1.0 + 2.0 * f64::error_bound(3))).min(t_max);
+
+        near.le(far)
+    }
+}
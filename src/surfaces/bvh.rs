@@ -0,0 +1,159 @@
+use std::array;
+use crate::math::point::Point;
+use crate::math::ray::Ray;
+use crate::math::ray_packet::RayPacket;
+use crate::math::simd;
+use crate::math::simd::{Kernel, Lanes};
+use crate::math::vector::Vector;
+use crate::surfaces::bounding_box::BoundingBox;
+
+const MAX_LEAF_SIZE: usize = 4;
+
+struct BvhNode {
+    bounds: BoundingBox,
+    start: usize,
+    count: usize,
+    second_child: usize
+}
+
+pub struct Bvh {
+    nodes: Vec<BvhNode>,
+    indices: Vec<usize>
+}
+
+impl Bvh {
+    pub fn new(bounds: &[BoundingBox]) -> Bvh {
+        let mut bvh = Bvh { nodes: Vec::new(), indices: (0..bounds.len()).collect() };
+
+        if !bounds.is_empty() {
+            bvh.build(bounds, 0, bounds.len());
+        }
+
+        bvh
+    }
+
+    pub fn intersect<F: Fn(usize) -> Option<f64>>(&self, ray: Ray, hit: F) -> Option<(usize, f64)> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+
+        let inverse_direction = Vector::new(1.0 / ray.direction.x, 1.0 / ray.direction.y, 1.0 / ray.direction.z);
+        let mut closest: Option<(usize, f64)> = None;
+        let mut stack = vec![0];
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let t_max = closest.map_or(ray.t_max, |(_, t)| t);
+
+            if node.bounds.intersect(ray, inverse_direction, t_max).is_none() {
+                continue;
+            }
+
+            if node.count == 0 {
+                stack.push(node.second_child);
+                stack.push(index + 1);
+                continue;
+            }
+
+            for &item in self.indices[node.start..node.start + node.count].iter() {
+                if let Some(t) = hit(item) {
+                    if closest.is_none_or(|(_, closest_t)| t < closest_t) {
+                        closest = Some((item, t));
+                    }
+                }
+            }
+        }
+
+        closest
+    }
+
+    pub fn intersect_packet<const N: usize, F: Fn(usize, usize) -> Option<f64>>(&self, packet: &RayPacket<N>, hit: F) -> [Option<(usize, f64)>; N] {
+        self.intersect_packet_masked(packet, |item, active| array::from_fn(|lane| if active[lane] { hit(item, lane) } else { None }))
+    }
+
+    pub fn intersect_packet_masked<const N: usize, F: Fn(usize, [bool; N]) -> [Option<f64>; N]>(&self, packet: &RayPacket<N>, hit: F) -> [Option<(usize, f64)>; N] {
+        simd::dispatch(PacketTraversal { bvh: self, packet, hit })
+    }
+
+    fn build(&mut self, bounds: &[BoundingBox], start: usize, end: usize) -> usize {
+        let node_bounds = self.indices[start..end].iter()
+            .fold(BoundingBox::EMPTY, |node_bounds, &index| BoundingBox::union(node_bounds, bounds[index]));
+
+        let index = self.nodes.len();
+        self.nodes.push(BvhNode { bounds: node_bounds, start, count: end - start, second_child: 0 });
+
+        if end - start <= MAX_LEAF_SIZE {
+            return index;
+        }
+
+        let centroids = self.indices[start..end].iter()
+            .map(|&index| bounds[index].centroid())
+            .collect::<Vec<Point>>();
+        let extent = BoundingBox::from_points(&centroids).extent();
+
+        let axis = if extent.x >= extent.y && extent.x >= extent.z { 0 } else if extent.y >= extent.z { 1 } else { 2 };
+        let component = |index: &usize| {
+            let centroid = bounds[*index].centroid();
+            [centroid.x, centroid.y, centroid.z][axis]
+        };
+
+        self.indices[start..end].sort_by(|a, b| component(a).total_cmp(&component(b)));
+
+        let middle = start + (end - start) / 2;
+        self.build(bounds, start, middle);
+        let second_child = self.build(bounds, middle, end);
+
+        self.nodes[index].count = 0;
+        self.nodes[index].second_child = second_child;
+        index
+    }
+}
+
+struct PacketTraversal<'a, const N: usize, F: Fn(usize, [bool; N]) -> [Option<f64>; N]> {
+    bvh: &'a Bvh,
+    packet: &'a RayPacket<N>,
+    hit: F
+}
+
+impl<const N: usize, F: Fn(usize, [bool; N]) -> [Option<f64>; N]> Kernel for PacketTraversal<'_, N, F> {
+    type Output = [Option<(usize, f64)>; N];
+
+    #[inline(always)]
+    fn run(self) -> Self::Output {
+        let mut closest: [Option<(usize, f64)>; N] = [None; N];
+        if self.bvh.nodes.is_empty() {
+            return closest;
+        }
+
+        let inverse_directions = self.packet.directions.reciprocal();
+        let mut stack = vec![0];
+
+        while let Some(index) = stack.pop() {
+            let node = &self.bvh.nodes[index];
+            let t_max = Lanes::from_fn(|lane| closest[lane].map_or(self.packet.t_maxs.lane(lane), |(_, t)| t));
+
+            let active = node.bounds.intersect_packet(self.packet.origins, inverse_directions, self.packet.t_mins, t_max);
+            if !active.contains(&true) {
+                continue;
+            }
+
+            if node.count == 0 {
+                stack.push(node.second_child);
+                stack.push(index + 1);
+                continue;
+            }
+
+            for &item in self.bvh.indices[node.start..node.start + node.count].iter() {
+                for (lane, hit) in (self.hit)(item, active).into_iter().enumerate() {
+                    if let Some(t) = hit.filter(|_| active[lane]) {
+                        if closest[lane].is_none_or(|(_, closest_t)| t < closest_t) {
+                            closest[lane] = Some((item, t));
+                        }
+                    }
+                }
+            }
+        }
+
+        closest
+    }
+}
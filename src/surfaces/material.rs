@@ -1,12 +1,125 @@
+use std::sync::Arc;
 use crate::math::color::Color;
+use crate::math::normal::Normal;
+use crate::math::vector::Vector;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::texture::Texture;
 
-#[derive(Copy, Clone)]
+const BUMP_DELTA: f64 = 0.0005;
+
+#[derive(Clone)]
 pub struct Material {
-    pub diffuse: Color
+    pub diffuse: Color,
+    pub diffuse_texture: Option<Arc<dyn Texture + Send + Sync>>,
+    pub normal_map: Option<Arc<dyn Texture + Send + Sync>>,
+    pub bump_map: Option<Arc<dyn Texture + Send + Sync>>,
+    pub bump_strength: f64,
+    pub emission: Color,
+    pub emission_texture: Option<Arc<dyn Texture + Send + Sync>>,
+    pub metallic: f64,
+    pub roughness: f64,
+    pub metallic_roughness_texture: Option<Arc<dyn Texture + Send + Sync>>
 }
 
 impl Material {
     pub fn new(diffuse: Color) -> Material {
-        Material { diffuse }
+        Material {
+            diffuse,
+            diffuse_texture: None,
+            normal_map: None,
+            bump_map: None,
+            bump_strength: 1.0,
+            emission: Color::ZERO,
+            emission_texture: None,
+            metallic: 1.0,
+            roughness: 0.0,
+            metallic_roughness_texture: None
+        }
+    }
+
+    pub fn textured(diffuse_texture: Arc<dyn Texture + Send + Sync>) -> Material {
+        Material { diffuse_texture: Some(diffuse_texture), ..Material::new(Color::WHITE) }
+    }
+
+    pub fn diffuse_at(&self, surface_point: &SurfacePoint) -> Color {
+        match &self.diffuse_texture {
+            Some(texture) => self.diffuse * texture.color(surface_point),
+            _ => self.diffuse
+        }
+    }
+
+    pub fn emission_at(&self, surface_point: &SurfacePoint) -> Color {
+        let emission = match &self.emission_texture {
+            Some(texture) => self.emission * texture.color(surface_point),
+            _ => self.emission
+        };
+
+        Color::with_alpha(emission, 0.0)
+    }
+
+    pub fn metallic_roughness_at(&self, surface_point: &SurfacePoint) -> (f64, f64) {
+        match &self.metallic_roughness_texture {
+            Some(texture) => {
+                let encoded = texture.color(surface_point);
+                (self.metallic * encoded.blue, self.roughness * encoded.green)
+            }
+            _ => (self.metallic, self.roughness)
+        }
+    }
+
+    pub fn reflectivity_at(&self, surface_point: &SurfacePoint) -> f64 {
+        let (metallic, roughness) = self.metallic_roughness_at(surface_point);
+        (1.0 - roughness.clamp(0.0, 1.0) * (1.0 - metallic.clamp(0.0, 1.0))).clamp(0.0, 1.0)
     }
-}
\ No newline at end of file
+
+    pub fn shading_normal(&self, surface_point: &SurfacePoint) -> Normal {
+        let normal = surface_point.shading_normal;
+        let (tangent, bitangent) = match Vector::rejection(surface_point.tangent, normal.vector()).normalized() {
+            Some(tangent) => {
+                let bitangent = Vector::rejection(Vector::rejection(surface_point.bitangent, normal.vector()), tangent).normalized()
+                    .unwrap_or_else(|| Vector::cross(normal.vector(), tangent));
+
+                (tangent, bitangent)
+            }
+            _ => normal.frame()
+        };
+
+        let normal = match &self.normal_map {
+            Some(normal_map) => {
+                let encoded = normal_map.color(surface_point);
+                let (x, y, z) = (2.0 * encoded.red - 1.0, 2.0 * encoded.green - 1.0, 2.0 * encoded.blue - 1.0);
+
+                Normal::new(tangent * x + bitangent * y + normal * z).unwrap_or(normal)
+            }
+            _ => normal
+        };
+
+        match &self.bump_map {
+            Some(bump_map) => {
+                let (dpdu, dpdv) = (Vector::rejection(surface_point.tangent, normal.vector()), Vector::rejection(surface_point.bitangent, normal.vector()));
+                if dpdu.is_approximately_zero() || dpdv.is_approximately_zero() {
+                    return normal;
+                }
+
+                let height = |du: f64, dv: f64| {
+                    let shifted = SurfacePoint {
+                        point: surface_point.point + dpdu * du + dpdv * dv,
+                        u: surface_point.u + du,
+                        v: surface_point.v + dv,
+                        ..surface_point.clone()
+                    };
+
+                    Color::luminance(bump_map.color(&shifted))
+                };
+
+                let center = height(0.0, 0.0);
+                let slope_u = (height(BUMP_DELTA, 0.0) - center) / BUMP_DELTA;
+                let slope_v = (height(0.0, BUMP_DELTA) - center) / BUMP_DELTA;
+
+                let bumped = Vector::cross(dpdu + normal * (self.bump_strength * slope_u), dpdv + normal * (self.bump_strength * slope_v));
+                Normal::new(bumped).map_or(normal, |bumped| bumped.face_forward(normal.vector()))
+            }
+            _ => normal
+        }
+    }
+}
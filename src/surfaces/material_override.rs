@@ -0,0 +1,39 @@
+use crate::math::ray::Ray;
+use crate::math::ray_packet::RayPacket;
+use crate::surfaces::bounding_box::BoundingBox;
+use crate::surfaces::material::Material;
+use crate::surfaces::surface::{Surface, SurfacePoint};
+
+pub struct MaterialOverride<S: Surface> {
+    material: Material,
+    surface: S
+}
+
+impl<S: Surface> MaterialOverride<S> {
+    pub fn new(material: Material, surface: S) -> MaterialOverride<S> {
+        MaterialOverride { material, surface }
+    }
+
+    fn overridden(&self, surface_point: SurfacePoint) -> SurfacePoint {
+        SurfacePoint { material: self.material.clone(), ..surface_point }
+    }
+}
+
+impl<S: Surface> Surface for MaterialOverride<S> {
+    fn intersect(&self, ray: Ray) -> Option<SurfacePoint> {
+        let surface_point = self.surface.intersect(ray)?;
+        Some(self.overridden(surface_point))
+    }
+
+    fn intersect_packet4(&self, packet: &RayPacket<4>) -> [Option<SurfacePoint>; 4] {
+        self.surface.intersect_packet4(packet).map(|surface_point| surface_point.map(|surface_point| self.overridden(surface_point)))
+    }
+
+    fn intersect_packet8(&self, packet: &RayPacket<8>) -> [Option<SurfacePoint>; 8] {
+        self.surface.intersect_packet8(packet).map(|surface_point| surface_point.map(|surface_point| self.overridden(surface_point)))
+    }
+
+    fn bounds(&self) -> Option<BoundingBox> {
+        self.surface.bounds()
+    }
+}
@@ -0,0 +1,160 @@
+use std::array;
+use crate::math::float::Float;
+use crate::math::matrix::Matrix;
+use crate::math::normal::Normal;
+use crate::math::point::Point;
+use crate::math::ray::Ray;
+use crate::math::ray_packet::RayPacket;
+use crate::math::vector::Vector;
+use crate::surfaces::bounding_box::BoundingBox;
+use crate::surfaces::bvh::Bvh;
+use crate::surfaces::material::Material;
+use crate::surfaces::surface::{Surface, SurfacePoint};
+
+pub struct Mesh<T: Float = f64> {
+    positions: Vec<Point<T>>,
+    normals: Option<Vec<Vector<T>>>,
+    uvs: Option<Vec<(T, T)>>,
+    triangles: Vec<[usize; 3]>,
+    pub material: Material,
+    bvh: Bvh
+}
+
+impl<T: Float> Mesh<T> {
+    pub fn new(positions: Vec<Point<T>>, normals: Option<Vec<Vector<T>>>, uvs: Option<Vec<(T, T)>>, triangles: Vec<[usize; 3]>, material: Material) -> Option<Mesh<T>> {
+        let vertex_count = positions.len();
+
+        if normals.as_ref().is_some_and(|normals| normals.len() != vertex_count)
+            || uvs.as_ref().is_some_and(|uvs| uvs.len() != vertex_count)
+            || triangles.iter().flatten().any(|&index| index >= vertex_count) {
+            return None;
+        }
+
+        let bounds = triangles.iter()
+            .map(|triangle| BoundingBox::from_points(&triangle.map(|index| positions[index].cast())))
+            .collect::<Vec<BoundingBox>>();
+        let bvh = Bvh::new(&bounds);
+
+        Some(Mesh { positions, normals, uvs, triangles, material, bvh })
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.triangles.len()
+    }
+
+    fn position(&self, index: usize) -> Point {
+        self.positions[index].cast()
+    }
+
+    fn barycentric(&self, ray: Ray, triangle: usize) -> Option<(f64, f64, f64)> {
+        let [a, b, c] = self.triangles[triangle].map(|index| self.position(index));
+        let (edge1, edge2) = (b - a, c - a);
+
+        let p = Vector::cross(ray.direction, edge2);
+        let determinant = Vector::dot(edge1, p);
+        if determinant.abs() < f64::EPSILON * ray.direction.length() * edge1.length() * edge2.length() {
+            return None;
+        }
+
+        let offset = ray.origin - a;
+        let u = Vector::dot(offset, p) / determinant;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = Vector::cross(offset, edge1);
+        let v = Vector::dot(ray.direction, q) / determinant;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = Vector::dot(edge2, q) / determinant;
+        let scale = (ray.origin - Point::ZERO).length() + (a - Point::ZERO).length() + edge1.length() + edge2.length();
+        let t_error = f64::error_bound(7) * scale * edge1.length() * edge2.length() / determinant.abs();
+
+        if t <= ray.t_min + t_error || t >= ray.t_max {
+            return None;
+        }
+
+        Some((t, u, v))
+    }
+
+    fn intersect_packet<const N: usize>(&self, packet: &RayPacket<N>) -> [Option<SurfacePoint>; N] {
+        let rays = packet.rays();
+        let hits = self.bvh.intersect_packet(packet, |triangle, lane| self.barycentric(rays[lane], triangle).map(|(t, _, _)| t));
+        array::from_fn(|lane| self.surface_point(rays[lane], hits[lane]?.0))
+    }
+
+    fn surface_point(&self, ray: Ray, triangle: usize) -> Option<SurfacePoint> {
+        let (t, u, v) = self.barycentric(ray, triangle)?;
+        let w = 1.0 - u - v;
+
+        let [ia, ib, ic] = self.triangles[triangle];
+        let [a, b, c] = [ia, ib, ic].map(|index| self.position(index) - Point::ZERO);
+        let (edge1, edge2) = (b - a, c - a);
+        let point = Point::ZERO + a * w + b * u + c * v;
+        let error = (a * w).absolute() + (b * u).absolute() + (c * v).absolute();
+
+        let geometric_normal = Normal::new(Vector::cross(edge1, edge2))?;
+        let normal = geometric_normal.face_forward(-ray.direction);
+
+        let shading_normal = match &self.normals {
+            Some(normals) => Normal::new(normals[ia].cast() * w + normals[ib].cast() * u + normals[ic].cast() * v).unwrap_or(geometric_normal),
+            _ => geometric_normal
+        };
+
+        let shading_normal = if Normal::dot(normal, geometric_normal.vector()) < 0.0 { -shading_normal } else { shading_normal };
+
+        let (texture_u, texture_v, tangent, bitangent) = match &self.uvs {
+            Some(uvs) => {
+                let [uv_a, uv_b, uv_c] = [ia, ib, ic].map(|index| (uvs[index].0.to_f64(), uvs[index].1.to_f64()));
+                let (du1, dv1) = (uv_b.0 - uv_a.0, uv_b.1 - uv_a.1);
+                let (du2, dv2) = (uv_c.0 - uv_a.0, uv_c.1 - uv_a.1);
+                let determinant = du1 * dv2 - du2 * dv1;
+
+                let (tangent, bitangent) = if determinant.abs() > f64::EPSILON {
+                    ((edge1 * dv2 - edge2 * dv1) / determinant, (edge2 * du1 - edge1 * du2) / determinant)
+                } else {
+                    (edge1, Vector::cross(geometric_normal.vector(), edge1))
+                };
+
+                (uv_a.0 * w + uv_b.0 * u + uv_c.0 * v, uv_a.1 * w + uv_b.1 * u + uv_c.1 * v, tangent, bitangent)
+            }
+            _ => (u, v, edge1, edge2)
+        };
+
+        Some(SurfacePoint {
+            t,
+            time: ray.time,
+            point,
+            error: error * f64::error_bound(7),
+            world_to_object: Matrix::IDENTITY,
+            normal,
+            shading_normal,
+            u: texture_u,
+            v: texture_v,
+            tangent,
+            bitangent,
+            material: self.material.clone()
+        })
+    }
+}
+
+impl<T: Float> Surface for Mesh<T> {
+    fn intersect(&self, ray: Ray) -> Option<SurfacePoint> {
+        let (triangle, _) = self.bvh.intersect(ray, |triangle| self.barycentric(ray, triangle).map(|(t, _, _)| t))?;
+        self.surface_point(ray, triangle)
+    }
+
+    fn intersect_packet4(&self, packet: &RayPacket<4>) -> [Option<SurfacePoint>; 4] {
+        self.intersect_packet(packet)
+    }
+
+    fn intersect_packet8(&self, packet: &RayPacket<8>) -> [Option<SurfacePoint>; 8] {
+        self.intersect_packet(packet)
+    }
+
+    fn bounds(&self) -> Option<BoundingBox> {
+        Some(BoundingBox::from_points(&self.positions.iter().map(|position| position.cast()).collect::<Vec<Point>>()))
+    }
+}
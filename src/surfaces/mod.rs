@@ -2,4 +2,8 @@ pub mod surface;
 pub mod material;
 pub mod sphere;
 pub mod transformed_surface;
-pub mod multiple_surfaces;
\ No newline at end of file
+pub mod multiple_surfaces;
+pub mod material_override;
+pub mod bounding_box;
+pub mod bvh;
+pub mod mesh;
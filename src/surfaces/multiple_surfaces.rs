@@ -1,21 +1,105 @@
+use std::array;
+use std::cell::RefCell;
 use std::cmp::Ordering;
 use crate::math::ray::Ray;
+use crate::math::ray_packet::RayPacket;
+use crate::math::simd::Lanes;
+use crate::surfaces::bounding_box::BoundingBox;
+use crate::surfaces::bvh::Bvh;
 use crate::surfaces::surface::{Surface, SurfacePoint};
 
 pub struct MultipleSurfaces {
-    surfaces: Vec<Box<dyn Surface + Sync + Send>>
+    surfaces: Vec<Box<dyn Surface + Sync + Send>>,
+    bounded: Vec<usize>,
+    unbounded: Vec<usize>,
+    bvh: Bvh
 }
 
 impl MultipleSurfaces {
     pub fn new(surfaces: Vec<Box<dyn Surface + Sync + Send>>) -> MultipleSurfaces {
-        MultipleSurfaces { surfaces }
+        let (mut bounded, mut unbounded, mut bounds) = (Vec::new(), Vec::new(), Vec::new());
+
+        for (index, surface) in surfaces.iter().enumerate() {
+            match surface.bounds() {
+                Some(surface_bounds) => {
+                    bounded.push(index);
+                    bounds.push(surface_bounds);
+                }
+                _ => unbounded.push(index)
+            }
+        }
+
+        MultipleSurfaces { surfaces, bounded, unbounded, bvh: Bvh::new(&bounds) }
+    }
+
+    fn closest(s1: Option<SurfacePoint>, s2: Option<SurfacePoint>) -> Option<SurfacePoint> {
+        match (s1, s2) {
+            (Some(s1), Some(s2)) => Some(if s2.t < s1.t { s2 } else { s1 }),
+            (s1, s2) => s1.or(s2)
+        }
+    }
+
+    fn intersect_packet<const N: usize, F: Fn(&dyn Surface, &RayPacket<N>) -> [Option<SurfacePoint>; N]>(&self, packet: &RayPacket<N>, intersect: F) -> [Option<SurfacePoint>; N] {
+        let closest: RefCell<[Option<SurfacePoint>; N]> = RefCell::new(array::from_fn(|_| None));
+
+        self.bvh.intersect_packet_masked(packet, |item, active| {
+            let t_maxs = Lanes::from_fn(|lane| match (active[lane], &closest.borrow()[lane]) {
+                (false, _) => packet.t_mins.lane(lane),
+                (true, Some(surface_point)) => surface_point.t,
+                _ => packet.t_maxs.lane(lane)
+            });
+
+            let hits = intersect(self.surfaces[self.bounded[item]].as_ref(), &RayPacket { t_maxs, ..*packet });
+            let mut closest = closest.borrow_mut();
+
+            array::from_fn(|lane| {
+                let t = hits[lane].as_ref().filter(|_| active[lane]).map(|surface_point| surface_point.t);
+                closest[lane] = Self::closest(closest[lane].take(), hits[lane].clone().filter(|_| active[lane]));
+                t
+            })
+        });
+
+        self.unbounded.iter()
+            .map(|&index| intersect(self.surfaces[index].as_ref(), packet))
+            .fold(closest.into_inner(), |closest, hits| {
+                let mut hits = hits.into_iter();
+                closest.map(|closest| Self::closest(closest, hits.next().flatten()))
+            })
     }
 }
 
 impl Surface for MultipleSurfaces {
     fn intersect(&self, ray: Ray) -> Option<SurfacePoint> {
-        self.surfaces.iter()
-            .filter_map(|surface| surface.intersect(ray))
+        let closest: RefCell<Option<SurfacePoint>> = RefCell::new(None);
+
+        self.bvh.intersect(ray, |item| {
+            let t_max = closest.borrow().as_ref().map_or(ray.t_max, |surface_point| surface_point.t);
+            let surface_point = self.surfaces[self.bounded[item]].intersect(ray.with_interval(ray.t_min, t_max))?;
+            let t = surface_point.t;
+
+            closest.replace_with(|closest| Self::closest(closest.take(), Some(surface_point)));
+            Some(t)
+        });
+
+        self.unbounded.iter()
+            .filter_map(|&index| self.surfaces[index].intersect(ray))
+            .chain(closest.into_inner())
             .min_by(|s1, s2| s1.t.partial_cmp(&s2.t).unwrap_or(Ordering::Equal))
     }
-}
\ No newline at end of file
+
+    fn intersect_packet4(&self, packet: &RayPacket<4>) -> [Option<SurfacePoint>; 4] {
+        self.intersect_packet(packet, |surface, packet| surface.intersect_packet4(packet))
+    }
+
+    fn intersect_packet8(&self, packet: &RayPacket<8>) -> [Option<SurfacePoint>; 8] {
+        self.intersect_packet(packet, |surface, packet| surface.intersect_packet8(packet))
+    }
+
+    fn bounds(&self) -> Option<BoundingBox> {
+        if !self.unbounded.is_empty() {
+            return None;
+        }
+
+        Some(self.surfaces.iter().filter_map(|surface| surface.bounds()).fold(BoundingBox::EMPTY, BoundingBox::union))
+    }
+}
@@ -1,6 +1,15 @@
+use std::array;
+use std::f64::consts::{PI, TAU};
+use crate::math::float::Float;
+use crate::math::matrix::Matrix;
+use crate::math::normal::Normal;
 use crate::math::point::Point;
 use crate::math::ray::Ray;
+use crate::math::ray_packet::RayPacket;
+use crate::math::simd;
+use crate::math::simd::{Kernel, Lanes, VectorPacket};
 use crate::math::vector::Vector;
+use crate::surfaces::bounding_box::BoundingBox;
 use crate::surfaces::material::Material;
 use crate::surfaces::surface::{Surface, SurfacePoint};
 
@@ -13,31 +22,129 @@ impl Sphere {
     pub fn new(radius: f64, material: Material) -> Sphere {
         Sphere { radius, material }
     }
+
+    fn nearest(ray: Ray, t1: f64, t2: f64, t_error: f64) -> Option<f64> {
+        let within = |t: f64| t > ray.t_min + t_error && t < ray.t_max;
+
+        if within(t1) { Some(t1) }
+            else if within(t2) { Some(t2) }
+            else { None }
+    }
+
+    fn t_error(projection: f64, root: f64, length_squared: f64, delta_magnitude: f64) -> f64 {
+        let delta_error = f64::error_bound(5) * delta_magnitude;
+        let root_error = (delta_error / (2.0 * root)).min(delta_error.sqrt());
+
+        (f64::error_bound(3) * (projection.abs() + root) + root_error) / length_squared
+    }
+
+    fn surface_point(&self, ray: Ray, t: f64) -> Option<SurfacePoint> {
+        let hit: Point = ray.point_at(t);
+        let point = Point::ZERO + (hit - Point::ZERO) * (self.radius / Point::distance(hit, Point::ZERO));
+        let normal = Normal::new(point - Point::ZERO)?;
+        let local = normal.vector();
+
+        let phi = local.x.atan2(local.z);
+        let theta = local.y.clamp(-1.0, 1.0).acos();
+
+        let (sine_phi, cosine_phi) = phi.sin_cos();
+        let (sine_theta, cosine_theta) = theta.sin_cos();
+
+        Some(SurfacePoint {
+            t,
+            time: ray.time,
+            point,
+            error: (point - Point::ZERO).absolute() * f64::error_bound(5),
+            world_to_object: Matrix::IDENTITY,
+            normal,
+            shading_normal: normal,
+            u: 0.5 + phi / TAU,
+            v: theta / PI,
+            tangent: Vector::new(cosine_phi, 0.0, -sine_phi) * (TAU * self.radius * sine_theta),
+            bitangent: Vector::new(cosine_theta * sine_phi, -sine_theta, cosine_theta * cosine_phi) * (PI * self.radius),
+            material: self.material.clone()
+        })
+    }
+
+    fn intersect_packet<const N: usize>(&self, packet: &RayPacket<N>) -> [Option<SurfacePoint>; N] {
+        let ts = simd::dispatch(SphereKernel { packet, radius: self.radius });
+        array::from_fn(|lane| self.surface_point(packet.ray(lane), ts[lane]?))
+    }
+}
+
+struct SphereKernel<'a, const N: usize> {
+    packet: &'a RayPacket<N>,
+    radius: f64
+}
+
+impl<const N: usize> Kernel for SphereKernel<'_, N> {
+    type Output = [Option<f64>; N];
+
+    #[inline(always)]
+    fn run(self) -> Self::Output {
+        let (origins, directions) = (self.packet.origins, self.packet.directions);
+        let projection = VectorPacket::dot(origins, directions);
+        let length_squared = directions.length_squared();
+
+        let delta = projection * projection
+            + length_squared * Lanes::splat(self.radius * self.radius)
+            - length_squared * origins.length_squared();
+
+        let delta_magnitude = projection * projection
+            + length_squared * Lanes::splat(self.radius * self.radius)
+            + length_squared * origins.length_squared();
+
+        let root = delta.sqrt();
+        let t1 = (-projection - root) / length_squared;
+        let t2 = (-projection + root) / length_squared;
+
+        array::from_fn(|lane| {
+            if delta.lane(lane) <= 0.0 {
+                return None;
+            }
+
+            let t_error = Sphere::t_error(projection.lane(lane), root.lane(lane), length_squared.lane(lane), delta_magnitude.lane(lane));
+            Sphere::nearest(self.packet.ray(lane), t1.lane(lane), t2.lane(lane), t_error)
+        })
+    }
 }
 
 impl Surface for Sphere {
     fn intersect(&self, ray: Ray) -> Option<SurfacePoint> {
-        let delta: f64 = Vector::dot(ray.origin - Point::ZERO, ray.direction).powi(2)
-            + ray.direction.length_squared() * self.radius.powi(2)
-            - ray.direction.length_squared() * (ray.origin - Point::ZERO).length_squared();
+        let origin = ray.origin - Point::ZERO;
+        let projection = Vector::dot(origin, ray.direction);
+        let length_squared = ray.direction.length_squared();
+
+        let delta: f64 = projection.powi(2)
+            + length_squared * self.radius.powi(2)
+            - length_squared * origin.length_squared();
 
-        if delta < f64::EPSILON {
+        if delta <= 0.0 {
             return None;
         }
 
-        let t1 = (-Vector::dot(ray.origin - Point::ZERO, ray.direction) - delta.sqrt()) / ray.direction.length_squared();
-        let t2 = (-Vector::dot(ray.origin - Point::ZERO, ray.direction) + delta.sqrt()) / ray.direction.length_squared();
+        let delta_magnitude = projection.powi(2)
+            + length_squared * self.radius.powi(2)
+            + length_squared * origin.length_squared();
 
-        let t = if t1 <= t2 && t1 / ray.direction.length() > 0.00001 { t1 }
-            else if t2 / ray.direction.length() > 0.00001 { t2 }
-            else { return None };
+        let root = delta.sqrt();
+        let t1 = (-projection - root) / length_squared;
+        let t2 = (-projection + root) / length_squared;
 
-        let point: Point = ray.point_at(t);
-        Some(SurfacePoint {
-            t,
-            point,
-            normal: point - Point::ZERO,
-            material: self.material
-        })
+        let t = Sphere::nearest(ray, t1, t2, Sphere::t_error(projection, root, length_squared, delta_magnitude))?;
+        self.surface_point(ray, t)
+    }
+
+    fn intersect_packet4(&self, packet: &RayPacket<4>) -> [Option<SurfacePoint>; 4] {
+        self.intersect_packet(packet)
+    }
+
+    fn intersect_packet8(&self, packet: &RayPacket<8>) -> [Option<SurfacePoint>; 8] {
+        self.intersect_packet(packet)
+    }
+
+    fn bounds(&self) -> Option<BoundingBox> {
+        let radius = self.radius.abs();
+        Some(BoundingBox::new(Point::new(-radius, -radius, -radius), Point::new(radius, radius, radius)))
     }
 }
\ No newline at end of file
@@ -1,17 +1,126 @@
+use std::sync::Arc;
+use crate::math::matrix::Matrix;
 use crate::math::normal::Normal;
 use crate::math::point::Point;
 use crate::math::ray::Ray;
+use crate::math::ray_packet::RayPacket;
+use crate::math::simd::SimdLevel;
 use crate::math::vector::Vector;
+use crate::surfaces::bounding_box::BoundingBox;
 use crate::surfaces::material::Material;
 
 pub trait Surface {
     fn intersect(&self, ray: Ray) -> Option<SurfacePoint>;
+
+    fn intersect_packet4(&self, packet: &RayPacket<4>) -> [Option<SurfacePoint>; 4] {
+        packet.rays().map(|ray| self.intersect(ray))
+    }
+
+    fn intersect_packet8(&self, packet: &RayPacket<8>) -> [Option<SurfacePoint>; 8] {
+        packet.rays().map(|ray| self.intersect(ray))
+    }
+
+    fn intersect_rays(&self, rays: &[Ray]) -> Vec<Option<SurfacePoint>> {
+        match SimdLevel::current().packet_width() {
+            8 => intersect_chunks(rays, |packet| self.intersect_packet8(packet)),
+            4 => intersect_chunks(rays, |packet| self.intersect_packet4(packet)),
+            _ => rays.iter().map(|&ray| self.intersect(ray)).collect()
+        }
+    }
+
+    fn bounds(&self) -> Option<BoundingBox> {
+        None
+    }
+}
+
+impl<S: Surface + ?Sized> Surface for Box<S> {
+    fn intersect(&self, ray: Ray) -> Option<SurfacePoint> {
+        (**self).intersect(ray)
+    }
+
+    fn intersect_packet4(&self, packet: &RayPacket<4>) -> [Option<SurfacePoint>; 4] {
+        (**self).intersect_packet4(packet)
+    }
+
+    fn intersect_packet8(&self, packet: &RayPacket<8>) -> [Option<SurfacePoint>; 8] {
+        (**self).intersect_packet8(packet)
+    }
+
+    fn bounds(&self) -> Option<BoundingBox> {
+        (**self).bounds()
+    }
+}
+
+impl<S: Surface + ?Sized> Surface for Arc<S> {
+    fn intersect(&self, ray: Ray) -> Option<SurfacePoint> {
+        (**self).intersect(ray)
+    }
+
+    fn intersect_packet4(&self, packet: &RayPacket<4>) -> [Option<SurfacePoint>; 4] {
+        (**self).intersect_packet4(packet)
+    }
+
+    fn intersect_packet8(&self, packet: &RayPacket<8>) -> [Option<SurfacePoint>; 8] {
+        (**self).intersect_packet8(packet)
+    }
+
+    fn bounds(&self) -> Option<BoundingBox> {
+        (**self).bounds()
+    }
 }
 
-#[derive(Copy, Clone)]
+fn intersect_chunks<const N: usize, F: Fn(&RayPacket<N>) -> [Option<SurfacePoint>; N]>(rays: &[Ray], intersect: F) -> Vec<Option<SurfacePoint>> {
+    let mut hits = Vec::with_capacity(rays.len());
+
+    for chunk in rays.chunks(N) {
+        if let Some(packet) = RayPacket::padded(chunk) {
+            hits.extend(intersect(&packet).into_iter().take(chunk.len()));
+        }
+    }
+
+    hits
+}
+
+#[derive(Clone)]
 pub struct SurfacePoint {
     pub t: f64,
+    pub time: f64,
     pub point: Point,
-    pub normal: Vector,
+    pub error: Vector,
+    pub world_to_object: Matrix,
+    pub normal: Normal,
+    pub shading_normal: Normal,
+    pub u: f64,
+    pub v: f64,
+    pub tangent: Vector,
+    pub bitangent: Vector,
     pub material: Material
-}
\ No newline at end of file
+}
+
+impl SurfacePoint {
+    pub fn shaded(self) -> SurfacePoint {
+        let shading_normal = self.material.shading_normal(&self);
+        SurfacePoint { shading_normal, ..self }
+    }
+
+    pub fn object_point(&self) -> Point {
+        self.world_to_object * self.point
+    }
+
+    pub fn offset_origin(&self, direction: Vector) -> Point {
+        let normal = self.normal.vector();
+        let distance = Vector::dot(normal.absolute(), self.error);
+        let offset = if Vector::dot(direction, normal) < 0.0 { normal * -distance } else { normal * distance };
+
+        let round = |value: f64, offset: f64| {
+            if offset > 0.0 { value.next_up() } else if offset < 0.0 { value.next_down() } else { value }
+        };
+
+        let origin = self.point + offset;
+        Point::new(round(origin.x, offset.x), round(origin.y, offset.y), round(origin.z, offset.z))
+    }
+
+    pub fn spawn_ray(&self, direction: Vector) -> Ray {
+        Ray::timed(self.offset_origin(direction), direction, self.time)
+    }
+}
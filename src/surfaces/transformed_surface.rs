@@ -1,39 +1,97 @@
 use crate::math::matrix::Matrix;
 use crate::math::ray::Ray;
+use crate::math::ray_packet::RayPacket;
+use crate::math::transformation::animated_transform::AnimatedTransform;
 use crate::math::transformation::transform::Transform;
 use crate::math::transformation::transformation::Transformation;
+use crate::surfaces::bounding_box::BoundingBox;
 use crate::surfaces::surface::{Surface, SurfacePoint};
 
 pub struct TransformedSurface<S: Surface> {
     transform_matrix: Matrix,
     inverse_transform: Matrix,
+    animation: Option<AnimatedTransform>,
     surface: S
 }
 
 impl<S: Surface> TransformedSurface<S> {
     pub fn new(transform: Transform, surface: S) -> TransformedSurface<S> {
-        TransformedSurface { transform_matrix: transform.matrix(), inverse_transform: transform.inverse().matrix(), surface }
+        TransformedSurface::from_matrices(transform.matrix(), transform.inverse_matrix(), surface)
+    }
+
+    pub fn from_matrices(transform_matrix: Matrix, inverse_transform: Matrix, surface: S) -> TransformedSurface<S> {
+        TransformedSurface { transform_matrix, inverse_transform, animation: None, surface }
+    }
+
+    pub fn from_matrix(transform_matrix: Matrix, surface: S) -> Option<TransformedSurface<S>> {
+        Some(TransformedSurface::from_matrices(transform_matrix, transform_matrix.inverse()?, surface))
+    }
+
+    pub fn animated(animation: AnimatedTransform, surface: S) -> TransformedSurface<S> {
+        let transform = animation.at(0.0);
+        TransformedSurface { transform_matrix: transform.matrix(), inverse_transform: transform.inverse_matrix(), animation: Some(animation), surface }
+    }
+
+    fn matrices_at(&self, time: f64) -> (Matrix, Matrix) {
+        match &self.animation {
+            Some(animation) => {
+                let transform = animation.at(time);
+                (transform.matrix(), transform.inverse_matrix())
+            }
+            _ => (self.transform_matrix, self.inverse_transform)
+        }
+    }
+
+    fn intersect_packet<const N: usize, F: Fn(&RayPacket<N>) -> [Option<SurfacePoint>; N]>(&self, packet: &RayPacket<N>, intersect: F) -> [Option<SurfacePoint>; N] {
+        if !packet.has_uniform_time() {
+            return packet.rays().map(|ray| self.intersect(ray));
+        }
+
+        let (transform_matrix, inverse_transform) = self.matrices_at(packet.times.lane(0));
+        intersect(&(inverse_transform * *packet))
+            .map(|surface_point| Self::to_world(transform_matrix, inverse_transform, surface_point?))
+    }
+
+    fn to_world(transform_matrix: Matrix, inverse_transform: Matrix, surface_point: SurfacePoint) -> Option<SurfacePoint> {
+        Some(SurfacePoint {
+            t: surface_point.t,
+            time: surface_point.time,
+            point: transform_matrix * surface_point.point,
+            error: transform_matrix.point_error(surface_point.point, surface_point.error),
+            world_to_object: surface_point.world_to_object * inverse_transform,
+            normal: surface_point.normal.transformed(inverse_transform)?,
+            shading_normal: surface_point.shading_normal.transformed(inverse_transform)?,
+            u: surface_point.u,
+            v: surface_point.v,
+            tangent: transform_matrix * surface_point.tangent,
+            bitangent: transform_matrix * surface_point.bitangent,
+            material: surface_point.material
+        })
     }
 }
 
 impl<S: Surface> Surface for TransformedSurface<S> {
     fn intersect(&self, ray: Ray) -> Option<SurfacePoint> {
-        let transformed_ray = self.inverse_transform * ray;
+        let (transform_matrix, inverse_transform) = self.matrices_at(ray.time);
+
+        let transformed_ray = inverse_transform * ray;
         let surface_point = self.surface.intersect(transformed_ray)?;
 
-        let real_t = surface_point.t * transformed_ray.direction.length();
+        Self::to_world(transform_matrix, inverse_transform, surface_point)
+    }
 
-        if real_t <= f64::EPSILON {
-            return None;
-        }
+    fn intersect_packet4(&self, packet: &RayPacket<4>) -> [Option<SurfacePoint>; 4] {
+        self.intersect_packet(packet, |packet| self.surface.intersect_packet4(packet))
+    }
 
-        let surface_point = SurfacePoint {
-            t: real_t,
-            point: self.transform_matrix * surface_point.point,
-            normal: (self.transform_matrix * surface_point.normal),
-            material: surface_point.material
-        };
+    fn intersect_packet8(&self, packet: &RayPacket<8>) -> [Option<SurfacePoint>; 8] {
+        self.intersect_packet(packet, |packet| self.surface.intersect_packet8(packet))
+    }
 
-        Some(surface_point)
+    fn bounds(&self) -> Option<BoundingBox> {
+        match self.animation {
+            Some(_) => None,
+            _ => Some(self.surface.bounds()?.transformed(self.transform_matrix))
+        }
     }
-}
\ No newline at end of file
+}
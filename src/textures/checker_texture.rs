@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use crate::math::color::Color;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::texture::{Texture, TextureSpace};
+
+pub struct CheckerTexture {
+    even: Arc<dyn Texture + Send + Sync>,
+    odd: Arc<dyn Texture + Send + Sync>,
+    frequency: f64,
+    space: TextureSpace
+}
+
+impl CheckerTexture {
+    pub fn new(even: Arc<dyn Texture + Send + Sync>, odd: Arc<dyn Texture + Send + Sync>, frequency: f64, space: TextureSpace) -> CheckerTexture {
+        CheckerTexture { even, odd, frequency, space }
+    }
+}
+
+impl Texture for CheckerTexture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color {
+        let point = self.space.coordinates(surface_point) * self.frequency;
+
+        let parity = match self.space {
+            TextureSpace::Uv => point.x.floor() as i64 + point.y.floor() as i64,
+            TextureSpace::Position => point.x.floor() as i64 + point.y.floor() as i64 + point.z.floor() as i64
+        };
+
+        if parity.rem_euclid(2) == 0 {
+            self.even.color(surface_point)
+        } else {
+            self.odd.color(surface_point)
+        }
+    }
+}
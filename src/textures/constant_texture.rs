@@ -0,0 +1,19 @@
+use crate::math::color::Color;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::texture::Texture;
+
+pub struct ConstantTexture {
+    color: Color
+}
+
+impl ConstantTexture {
+    pub fn new(color: Color) -> ConstantTexture {
+        ConstantTexture { color }
+    }
+}
+
+impl Texture for ConstantTexture {
+    fn color(&self, _surface_point: &SurfacePoint) -> Color {
+        self.color
+    }
+}
@@ -0,0 +1,27 @@
+use crate::math::color::Color;
+use crate::math::point::Point;
+use crate::math::vector::Vector;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::texture::{Texture, TextureSpace};
+
+pub struct GradientTexture {
+    from: Color,
+    to: Color,
+    axis: Vector,
+    space: TextureSpace
+}
+
+impl GradientTexture {
+    pub fn new(from: Color, to: Color, axis: Vector, space: TextureSpace) -> GradientTexture {
+        GradientTexture { from, to, axis, space }
+    }
+}
+
+impl Texture for GradientTexture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color {
+        let coordinates = self.space.coordinates(surface_point) - Point::ZERO;
+        let t = (Vector::dot(coordinates, self.axis.inverse_or_zero())).clamp(0.0, 1.0);
+
+        self.from * (1.0 - t) + self.to * t
+    }
+}
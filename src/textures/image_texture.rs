@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+use crate::math::color::Color;
+use crate::renderer::image::Image;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::texture::Texture;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum WrapMode {
+    Repeat,
+    MirroredRepeat,
+    Clamp
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Filter {
+    Nearest,
+    Bilinear
+}
+
+pub struct ImageTexture {
+    image: Image,
+    wrap_mode: WrapMode,
+    filter: Filter,
+    path: Option<PathBuf>
+}
+
+impl ImageTexture {
+    pub fn new(image: Image, wrap_mode: WrapMode, filter: Filter) -> ImageTexture {
+        ImageTexture { image, wrap_mode, filter, path: None }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P, wrap_mode: WrapMode, filter: Filter) -> std::io::Result<ImageTexture> {
+        let image = Image::read(path.as_ref())?;
+        Ok(ImageTexture { path: Some(path.as_ref().to_path_buf()), ..ImageTexture::new(image, wrap_mode, filter) })
+    }
+
+    pub fn color_at_uv(&self, u: f64, v: f64) -> Color {
+        let (width, height) = self.image.resolution();
+        let (x, y) = (u * width as f64 - 0.5, v * height as f64 - 0.5);
+
+        match self.filter {
+            Filter::Nearest => self.texel(x.round() as i64, y.round() as i64),
+            Filter::Bilinear => {
+                let (x0, y0) = (x.floor(), y.floor());
+                let (tx, ty) = (x - x0, y - y0);
+                let (x0, y0) = (x0 as i64, y0 as i64);
+
+                let top = self.texel(x0, y0) * (1.0 - tx) + self.texel(x0 + 1, y0) * tx;
+                let bottom = self.texel(x0, y0 + 1) * (1.0 - tx) + self.texel(x0 + 1, y0 + 1) * tx;
+
+                top * (1.0 - ty) + bottom * ty
+            }
+        }
+    }
+
+    fn texel(&self, x: i64, y: i64) -> Color {
+        let (width, height) = self.image.resolution();
+        let x = ImageTexture::wrap(x, width, self.wrap_mode);
+        let y = ImageTexture::wrap(y, height, self.wrap_mode);
+
+        self.image.color_at(x, y).unwrap_or(Color::BLACK)
+    }
+
+    fn wrap(coordinate: i64, size: usize, wrap_mode: WrapMode) -> usize {
+        let size = size.max(1) as i64;
+
+        let wrapped = match wrap_mode {
+            WrapMode::Repeat => coordinate.rem_euclid(size),
+            WrapMode::MirroredRepeat => {
+                let period = coordinate.rem_euclid(2 * size);
+                if period < size { period } else { 2 * size - 1 - period }
+            }
+            WrapMode::Clamp => coordinate.clamp(0, size - 1)
+        };
+
+        wrapped as usize
+    }
+}
+
+impl Texture for ImageTexture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color {
+        self.color_at_uv(surface_point.u, surface_point.v)
+    }
+
+    fn source_path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+}
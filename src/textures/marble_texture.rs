@@ -0,0 +1,41 @@
+use crate::math::color::Color;
+use crate::math::point::Point;
+use crate::math::vector::Vector;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::noise::{Noise, NoiseKind};
+use crate::textures::texture::Texture;
+
+pub struct MarbleTexture {
+    noise: Noise,
+    base: Color,
+    vein: Color,
+    direction: Vector,
+    frequency: f64,
+    distortion: f64
+}
+
+impl MarbleTexture {
+    const OCTAVES: usize = 6;
+
+    pub fn new(base: Color, vein: Color, direction: Vector, frequency: f64, distortion: f64) -> MarbleTexture {
+        MarbleTexture {
+            noise: Noise::new(NoiseKind::Perlin, 7),
+            base,
+            vein,
+            direction: direction.normalized_or_zero(),
+            frequency,
+            distortion
+        }
+    }
+}
+
+impl Texture for MarbleTexture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color {
+        let point = surface_point.object_point() * self.frequency;
+        let turbulence = self.noise.turbulence(point, MarbleTexture::OCTAVES, 2.0, 0.5);
+        let phase = Vector::dot(point - Point::ZERO, self.direction) + self.distortion * turbulence;
+
+        let t = (0.5 + 0.5 * phase.sin()).powf(0.5);
+        self.vein * (1.0 - t) + self.base * t
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+use crate::math::color::Color;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::texture::Texture;
+
+pub struct MixTexture {
+    a: Arc<dyn Texture + Send + Sync>,
+    b: Arc<dyn Texture + Send + Sync>,
+    factor: Arc<dyn Texture + Send + Sync>
+}
+
+impl MixTexture {
+    pub fn new(a: Arc<dyn Texture + Send + Sync>, b: Arc<dyn Texture + Send + Sync>, factor: Arc<dyn Texture + Send + Sync>) -> MixTexture {
+        MixTexture { a, b, factor }
+    }
+}
+
+impl Texture for MixTexture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color {
+        let t = Color::luminance(self.factor.color(surface_point)).clamp(0.0, 1.0);
+        self.a.color(surface_point) * (1.0 - t) + self.b.color(surface_point) * t
+    }
+}
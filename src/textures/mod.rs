@@ -0,0 +1,13 @@
+pub mod texture;
+pub mod noise;
+pub mod constant_texture;
+pub mod image_texture;
+pub mod checker_texture;
+pub mod gradient_texture;
+pub mod noise_texture;
+pub mod marble_texture;
+pub mod wood_texture;
+pub mod voronoi_texture;
+pub mod mix_texture;
+pub mod scale_texture;
+pub mod remap_texture;
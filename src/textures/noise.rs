@@ -0,0 +1,137 @@
+use crate::math::point::Point;
+use crate::math::sampling;
+
+const GRADIENTS: [(f64, f64, f64); 12] = [
+    (1.0, 1.0, 0.0), (-1.0, 1.0, 0.0), (1.0, -1.0, 0.0), (-1.0, -1.0, 0.0),
+    (1.0, 0.0, 1.0), (-1.0, 0.0, 1.0), (1.0, 0.0, -1.0), (-1.0, 0.0, -1.0),
+    (0.0, 1.0, 1.0), (0.0, -1.0, 1.0), (0.0, 1.0, -1.0), (0.0, -1.0, -1.0)
+];
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum NoiseKind {
+    Perlin,
+    Simplex
+}
+
+pub struct Noise {
+    kind: NoiseKind,
+    permutation: Vec<usize>
+}
+
+impl Noise {
+    pub fn new(kind: NoiseKind, seed: u64) -> Noise {
+        let mut table: Vec<usize> = (0..256).collect();
+
+        for index in (1..table.len()).rev() {
+            let other = (sampling::hash(seed ^ index as u64) % (index as u64 + 1)) as usize;
+            table.swap(index, other);
+        }
+
+        let permutation = table.iter().chain(table.iter()).copied().collect();
+        Noise { kind, permutation }
+    }
+
+    pub fn value(&self, point: Point) -> f64 {
+        match self.kind {
+            NoiseKind::Perlin => self.perlin(point),
+            NoiseKind::Simplex => self.simplex(point)
+        }
+    }
+
+    pub fn fbm(&self, point: Point, octaves: usize, lacunarity: f64, gain: f64) -> f64 {
+        let (mut sum, mut amplitude, mut frequency, mut normalization) = (0.0, 1.0, 1.0, 0.0);
+
+        for _ in 0..octaves.max(1) {
+            sum += amplitude * self.value(point * frequency);
+            normalization += amplitude;
+            amplitude *= gain;
+            frequency *= lacunarity;
+        }
+
+        sum / normalization
+    }
+
+    pub fn turbulence(&self, point: Point, octaves: usize, lacunarity: f64, gain: f64) -> f64 {
+        let (mut sum, mut amplitude, mut frequency, mut normalization) = (0.0, 1.0, 1.0, 0.0);
+
+        for _ in 0..octaves.max(1) {
+            sum += amplitude * self.value(point * frequency).abs();
+            normalization += amplitude;
+            amplitude *= gain;
+            frequency *= lacunarity;
+        }
+
+        sum / normalization
+    }
+
+    fn hash(&self, x: i64, y: i64, z: i64) -> usize {
+        let x = x.rem_euclid(256) as usize;
+        let y = y.rem_euclid(256) as usize;
+        let z = z.rem_euclid(256) as usize;
+
+        self.permutation[self.permutation[self.permutation[x] + y] + z]
+    }
+
+    fn gradient(&self, hash: usize, x: f64, y: f64, z: f64) -> f64 {
+        let (gx, gy, gz) = GRADIENTS[hash % GRADIENTS.len()];
+        gx * x + gy * y + gz * z
+    }
+
+    fn perlin(&self, point: Point) -> f64 {
+        let (x0, y0, z0) = (point.x.floor(), point.y.floor(), point.z.floor());
+        let (x, y, z) = (point.x - x0, point.y - y0, point.z - z0);
+        let (xi, yi, zi) = (x0 as i64, y0 as i64, z0 as i64);
+
+        let fade = |t: f64| t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+        let lerp = |t: f64, a: f64, b: f64| a + t * (b - a);
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let corner = |dx: i64, dy: i64, dz: i64| {
+            self.gradient(self.hash(xi + dx, yi + dy, zi + dz), x - dx as f64, y - dy as f64, z - dz as f64)
+        };
+
+        lerp(w,
+            lerp(v, lerp(u, corner(0, 0, 0), corner(1, 0, 0)), lerp(u, corner(0, 1, 0), corner(1, 1, 0))),
+            lerp(v, lerp(u, corner(0, 0, 1), corner(1, 0, 1)), lerp(u, corner(0, 1, 1), corner(1, 1, 1)))
+        )
+    }
+
+    fn simplex(&self, point: Point) -> f64 {
+        const SKEW: f64 = 1.0 / 3.0;
+        const UNSKEW: f64 = 1.0 / 6.0;
+
+        let skew = (point.x + point.y + point.z) * SKEW;
+        let (i, j, k) = ((point.x + skew).floor(), (point.y + skew).floor(), (point.z + skew).floor());
+        let unskew = (i + j + k) * UNSKEW;
+        let (x0, y0, z0) = (point.x - (i - unskew), point.y - (j - unskew), point.z - (k - unskew));
+
+        let (first, second) = if x0 >= y0 {
+            if y0 >= z0 { ((1, 0, 0), (1, 1, 0)) }
+            else if x0 >= z0 { ((1, 0, 0), (1, 0, 1)) }
+            else { ((0, 0, 1), (1, 0, 1)) }
+        } else if y0 < z0 { ((0, 0, 1), (0, 1, 1)) }
+            else if x0 < z0 { ((0, 1, 0), (0, 1, 1)) }
+            else { ((0, 1, 0), (1, 1, 0)) };
+
+        let (i, j, k) = (i as i64, j as i64, k as i64);
+        let corners = [(0, 0, 0), first, second, (1, 1, 1)];
+
+        let sum: f64 = corners.iter()
+            .enumerate()
+            .map(|(index, (di, dj, dk))| {
+                let offset = index as f64 * UNSKEW;
+                let (x, y, z) = (x0 - *di as f64 + offset, y0 - *dj as f64 + offset, z0 - *dk as f64 + offset);
+                let falloff = 0.6 - x * x - y * y - z * z;
+
+                if falloff < 0.0 {
+                    return 0.0;
+                }
+
+                let falloff = falloff * falloff;
+                falloff * falloff * self.gradient(self.hash(i + di, j + dj, k + dk), x, y, z)
+            })
+            .sum();
+
+        32.0 * sum
+    }
+}
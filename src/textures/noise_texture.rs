@@ -0,0 +1,51 @@
+use crate::math::color::Color;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::noise::{Noise, NoiseKind};
+use crate::textures::texture::{Texture, TextureSpace};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Fractal {
+    Single,
+    Fbm,
+    Turbulence
+}
+
+pub struct NoiseTexture {
+    noise: Noise,
+    fractal: Fractal,
+    octaves: usize,
+    frequency: f64,
+    space: TextureSpace
+}
+
+impl NoiseTexture {
+    pub const LACUNARITY: f64 = 2.0;
+    pub const GAIN: f64 = 0.5;
+
+    pub fn new(kind: NoiseKind, seed: u64, fractal: Fractal, octaves: usize, frequency: f64, space: TextureSpace) -> NoiseTexture {
+        NoiseTexture { noise: Noise::new(kind, seed), fractal, octaves, frequency, space }
+    }
+
+    pub fn perlin(frequency: f64) -> NoiseTexture {
+        NoiseTexture::new(NoiseKind::Perlin, 0, Fractal::Single, 1, frequency, TextureSpace::Position)
+    }
+
+    pub fn simplex(frequency: f64) -> NoiseTexture {
+        NoiseTexture::new(NoiseKind::Simplex, 0, Fractal::Single, 1, frequency, TextureSpace::Position)
+    }
+}
+
+impl Texture for NoiseTexture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color {
+        let point = self.space.coordinates(surface_point) * self.frequency;
+
+        let value = match self.fractal {
+            Fractal::Single => 0.5 + 0.5 * self.noise.value(point),
+            Fractal::Fbm => 0.5 + 0.5 * self.noise.fbm(point, self.octaves, NoiseTexture::LACUNARITY, NoiseTexture::GAIN),
+            Fractal::Turbulence => self.noise.turbulence(point, self.octaves, NoiseTexture::LACUNARITY, NoiseTexture::GAIN)
+        };
+
+        let value = value.clamp(0.0, 1.0);
+        Color::solid(value, value, value)
+    }
+}
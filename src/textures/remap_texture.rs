@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use crate::math::color::Color;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::texture::Texture;
+
+pub struct RemapTexture {
+    texture: Arc<dyn Texture + Send + Sync>,
+    from: (f64, f64),
+    low: Color,
+    high: Color
+}
+
+impl RemapTexture {
+    pub fn new(texture: Arc<dyn Texture + Send + Sync>, from: (f64, f64), low: Color, high: Color) -> RemapTexture {
+        RemapTexture { texture, from, low, high }
+    }
+}
+
+impl Texture for RemapTexture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color {
+        let value = Color::luminance(self.texture.color(surface_point));
+        let range = self.from.1 - self.from.0;
+        let t = if range != 0.0 { ((value - self.from.0) / range).clamp(0.0, 1.0) } else { 0.0 };
+
+        self.low * (1.0 - t) + self.high * t
+    }
+}
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+use crate::math::color::Color;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::texture::Texture;
+
+pub struct ScaleTexture {
+    texture: Arc<dyn Texture + Send + Sync>,
+    scale: Arc<dyn Texture + Send + Sync>
+}
+
+impl ScaleTexture {
+    pub fn new(texture: Arc<dyn Texture + Send + Sync>, scale: Arc<dyn Texture + Send + Sync>) -> ScaleTexture {
+        ScaleTexture { texture, scale }
+    }
+}
+
+impl Texture for ScaleTexture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color {
+        self.texture.color(surface_point) * self.scale.color(surface_point)
+    }
+}
@@ -0,0 +1,27 @@
+use std::path::Path;
+use crate::math::color::Color;
+use crate::math::point::Point;
+use crate::surfaces::surface::SurfacePoint;
+
+pub trait Texture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color;
+
+    fn source_path(&self) -> Option<&Path> {
+        None
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TextureSpace {
+    Uv,
+    Position
+}
+
+impl TextureSpace {
+    pub fn coordinates(self, surface_point: &SurfacePoint) -> Point {
+        match self {
+            TextureSpace::Uv => Point::new(surface_point.u, surface_point.v, 0.0),
+            TextureSpace::Position => surface_point.object_point()
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::math::color::Color;
+use crate::math::point::Point;
+use crate::math::sampling;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::texture::{Texture, TextureSpace};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum VoronoiOutput {
+    Distance,
+    Border,
+    Cell
+}
+
+pub struct VoronoiTexture {
+    seed: u64,
+    frequency: f64,
+    output: VoronoiOutput,
+    space: TextureSpace
+}
+
+impl VoronoiTexture {
+    pub fn new(seed: u64, frequency: f64, output: VoronoiOutput, space: TextureSpace) -> VoronoiTexture {
+        VoronoiTexture { seed, frequency, output, space }
+    }
+
+    fn cell_hash(&self, x: i64, y: i64, z: i64) -> u64 {
+        sampling::hash(self.seed ^ sampling::hash(x as u64 ^ sampling::hash(y as u64 ^ sampling::hash(z as u64))))
+    }
+
+    fn feature_point(&self, x: i64, y: i64, z: i64) -> Point {
+        let hash = self.cell_hash(x, y, z);
+        let flat = self.space == TextureSpace::Uv;
+
+        Point::new(
+            x as f64 + sampling::hash_to_unit(hash),
+            y as f64 + sampling::hash_to_unit(hash ^ 1),
+            if flat { 0.0 } else { z as f64 + sampling::hash_to_unit(hash ^ 2) }
+        )
+    }
+}
+
+impl Texture for VoronoiTexture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color {
+        let point = self.space.coordinates(surface_point) * self.frequency;
+        let (cx, cy, cz) = (point.x.floor() as i64, point.y.floor() as i64, point.z.floor() as i64);
+        let depth = if self.space == TextureSpace::Uv { 0..=0 } else { -1..=1 };
+
+        let (mut closest, mut second, mut closest_cell) = (f64::MAX, f64::MAX, 0);
+
+        for dz in depth {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let (x, y, z) = (cx + dx, cy + dy, cz + dz);
+                    let distance = Point::distance(point, self.feature_point(x, y, z));
+
+                    if distance < closest {
+                        second = closest;
+                        closest = distance;
+                        closest_cell = self.cell_hash(x, y, z);
+                    } else if distance < second {
+                        second = distance;
+                    }
+                }
+            }
+        }
+
+        match self.output {
+            VoronoiOutput::Distance => {
+                let value = closest.clamp(0.0, 1.0);
+                Color::solid(value, value, value)
+            }
+            VoronoiOutput::Border => {
+                let value = (second - closest).clamp(0.0, 1.0);
+                Color::solid(value, value, value)
+            }
+            VoronoiOutput::Cell => Color::solid(
+                sampling::hash_to_unit(closest_cell),
+                sampling::hash_to_unit(closest_cell ^ 1),
+                sampling::hash_to_unit(closest_cell ^ 2)
+            )
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::math::color::Color;
+use crate::math::point::Point;
+use crate::math::vector::Vector;
+use crate::surfaces::surface::SurfacePoint;
+use crate::textures::noise::{Noise, NoiseKind};
+use crate::textures::texture::Texture;
+
+pub struct WoodTexture {
+    noise: Noise,
+    light: Color,
+    dark: Color,
+    axis: Vector,
+    ring_frequency: f64,
+    distortion: f64
+}
+
+impl WoodTexture {
+    const OCTAVES: usize = 3;
+
+    pub fn new(light: Color, dark: Color, axis: Vector, ring_frequency: f64, distortion: f64) -> WoodTexture {
+        WoodTexture {
+            noise: Noise::new(NoiseKind::Perlin, 13),
+            light,
+            dark,
+            axis: axis.normalized().unwrap_or(Vector::UP),
+            ring_frequency,
+            distortion
+        }
+    }
+}
+
+impl Texture for WoodTexture {
+    fn color(&self, surface_point: &SurfacePoint) -> Color {
+        let point = surface_point.object_point();
+        let radius = Vector::scalar_rejection(point - Point::ZERO, self.axis);
+        let grain = self.noise.fbm(point * self.ring_frequency, WoodTexture::OCTAVES, 2.0, 0.5);
+
+        let rings = (radius * self.ring_frequency + self.distortion * grain).fract().abs();
+        let t = rings * rings * (3.0 - 2.0 * rings);
+
+        self.light * (1.0 - t) + self.dark * t
+    }
+}
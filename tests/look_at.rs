@@ -0,0 +1,38 @@
+use moonshade_raytracer::math::point::Point;
+use moonshade_raytracer::math::transformation::transform::Transform;
+use moonshade_raytracer::math::transformation::transformation::Transformation;
+use moonshade_raytracer::math::vector::Vector;
+use moonshade_raytracer::renderer::lens_shader::LensShader;
+use moonshade_raytracer::renderer::lens_shader::plane_perspective_lens_shader::PlanePerspectiveLensShader;
+use moonshade_raytracer::renderer::lens_shader::transformed_lens_shader::TransformedLensShader;
+
+#[test]
+fn look_at_flips_only_the_camera_y_axis() {
+    let transform = Transform::look_at(Point::ZERO, Point::new(0.0, 0.0, 1.0), Vector::UP).unwrap();
+    let right = transform.matrix() * Vector::new(1.0, 0.0, 0.0);
+    let down = transform.matrix() * Vector::new(0.0, 1.0, 0.0);
+    let forward = transform.matrix() * Vector::new(0.0, 0.0, 1.0);
+
+    assert!((right.x - 1.0).abs() < 1e-12 && right.y.abs() < 1e-12 && right.z.abs() < 1e-12);
+    assert!((down.y + 1.0).abs() < 1e-12 && down.x.abs() < 1e-12 && down.z.abs() < 1e-12);
+    assert!((forward.z - 1.0).abs() < 1e-12);
+    assert!((transform.matrix().determinant() + 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn points_around_target_project_upright_and_unmirrored() {
+    let eye = Point::new(0.0, 2.0, -10.0);
+    let lens_shader = PlanePerspectiveLensShader::from_vertical_fov(1.0, 1.0);
+    let camera = TransformedLensShader::look_at(eye, Point::ZERO, Vector::UP, lens_shader).unwrap();
+
+    let point_at_target = |x: f64, y: f64| {
+        let ray = camera.ray_to_lens_point(x, y, 0.5, 0.5).unwrap();
+        let t = Vector::dot(Point::ZERO - ray.origin, ray.direction) / ray.direction.length_squared();
+        ray.point_at(t)
+    };
+
+    assert!(point_at_target(0.5, 0.25).y > 0.0);
+    assert!(point_at_target(0.5, 0.75).y < 0.0);
+    assert!(point_at_target(0.9, 0.5).x > 0.0);
+    assert!(point_at_target(0.1, 0.5).x < 0.0);
+}
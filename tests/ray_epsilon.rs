@@ -0,0 +1,114 @@
+use std::f64::consts::TAU;
+use moonshade_raytracer::math::color::Color;
+use moonshade_raytracer::math::point::Point;
+use moonshade_raytracer::math::ray::Ray;
+use moonshade_raytracer::math::transformation::transform::Transform;
+use moonshade_raytracer::math::vector::Vector;
+use moonshade_raytracer::surfaces::material::Material;
+use moonshade_raytracer::surfaces::mesh::Mesh;
+use moonshade_raytracer::surfaces::sphere::Sphere;
+use moonshade_raytracer::surfaces::surface::Surface;
+use moonshade_raytracer::surfaces::transformed_surface::TransformedSurface;
+
+const SCALES: [f64; 3] = [1e-4, 1.0, 1e6];
+
+fn cube(size: f64) -> Mesh {
+    let positions = (0..8)
+        .map(|corner| Point::new(
+            if corner & 1 == 0 { -size } else { size },
+            if corner & 2 == 0 { -size } else { size },
+            if corner & 4 == 0 { -size } else { size }
+        ))
+        .collect();
+
+    let faces = [[0, 1, 3, 2], [4, 6, 7, 5], [0, 4, 5, 1], [2, 3, 7, 6], [0, 2, 6, 4], [1, 5, 7, 3]];
+    let triangles = faces.iter().flat_map(|&[a, b, c, d]| [[a, b, c], [a, c, d]]).collect();
+
+    Mesh::new(positions, None, None, triangles, Material::new(Color::WHITE)).unwrap()
+}
+
+fn directions() -> impl Iterator<Item = Vector> {
+    (0..200).map(|index| {
+        let (a, b) = (index as f64 * 0.618_034, index as f64 * 0.414_214);
+        Vector::new((a * TAU).sin() * 0.15, (b * TAU).cos() * 0.15, 1.0)
+    })
+}
+
+fn check_convex<S: Surface>(surface: &S, center: Point, scale: f64) {
+    let origin = center - Vector::FORWARD * (4.0 * scale);
+
+    for direction in directions() {
+        let ray = Ray::new(origin, direction * scale);
+        let hit = surface.intersect(ray).expect("a ray aimed at the object missed it");
+
+        let normal = hit.normal.vector();
+        let reflected = Vector::reflection(ray.direction, normal);
+        assert!(surface.intersect(hit.spawn_ray(reflected)).is_none(), "a reflected ray hit the surface it left at scale {}", scale);
+
+        let inward = hit.spawn_ray(ray.direction);
+        let exit = surface.intersect(inward).expect("a ray entering the object did not leave it");
+        assert!(Point::distance(exit.point, hit.point) > 0.01 * scale, "a ray entering the object hit its own origin at scale {}", scale);
+
+        let back = exit.spawn_ray(-ray.direction);
+        let entry = surface.intersect(back).expect("a ray sent back through the object missed the entry side");
+        assert!(Point::distance(entry.point, hit.point) < 1e-6 * scale);
+    }
+}
+
+#[test]
+fn spheres_avoid_self_intersection_at_every_scale() {
+    for scale in SCALES {
+        check_convex(&Sphere::new(scale, Material::new(Color::WHITE)), Point::ZERO, scale);
+
+        let center = Point::new(1e3 * scale, -2e3 * scale, 5e2 * scale);
+        let moved = TransformedSurface::new(Transform::translation(center - Point::ZERO), Sphere::new(scale, Material::new(Color::WHITE)));
+        check_convex(&moved, center, scale);
+
+        let scaled = TransformedSurface::new(Transform::scale(Vector::ONE * scale), Sphere::new(1.0, Material::new(Color::WHITE)));
+        check_convex(&scaled, Point::ZERO, scale);
+    }
+}
+
+#[test]
+fn triangles_avoid_self_intersection_at_every_scale() {
+    for scale in SCALES {
+        check_convex(&cube(scale), Point::ZERO, scale);
+
+        let center = Point::new(-1e3 * scale, 3e2 * scale, 2e3 * scale);
+        let moved = TransformedSurface::new(Transform::translation(center - Point::ZERO), cube(scale));
+        check_convex(&moved, center, scale);
+    }
+}
+
+#[test]
+fn grazing_rays_still_hit_spheres() {
+    for scale in SCALES {
+        let sphere = Sphere::new(scale, Material::new(Color::WHITE));
+
+        for height in [0.999, 0.999_999, 1.0 - 1e-12, 1.0 - 1e-14] {
+            let ray = Ray::new(Point::new(0.0, height * scale, -4.0 * scale), Vector::FORWARD * scale);
+            assert!(sphere.intersect(ray).is_some(), "a grazing ray at height {} missed at scale {}", height, scale);
+        }
+    }
+}
+
+#[test]
+fn ray_intervals_limit_hits() {
+    let sphere = Sphere::new(1.0, Material::new(Color::WHITE));
+    let ray = Ray::new(Point::new(0.0, 0.0, -4.0), Vector::FORWARD * 2.0);
+
+    assert!((sphere.intersect(ray).unwrap().t - 1.5).abs() < 1e-12);
+    assert!((sphere.intersect(ray.with_interval(2.0, f64::INFINITY)).unwrap().t - 2.5).abs() < 1e-12);
+    assert!(sphere.intersect(ray.with_interval(0.0, 1.4)).is_none());
+}
+
+#[test]
+fn normalized_rays_rescale_their_interval() {
+    let ray: Ray = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 3.0, 4.0)).with_interval(1.0, 3.0);
+    let normalized = ray.normalized().unwrap();
+
+    assert!((normalized.t_min - 5.0).abs() < 1e-12 && (normalized.t_max - 15.0).abs() < 1e-12);
+    assert!(Point::distance(normalized.point_at(normalized.t_min), ray.point_at(ray.t_min)) < 1e-12);
+    assert!(Point::distance(normalized.point_at(normalized.t_max), ray.point_at(ray.t_max)) < 1e-12);
+}
+
@@ -0,0 +1,31 @@
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::Duration;
+use moonshade_raytracer::math::color::Color;
+use moonshade_raytracer::math::vector::Vector;
+use moonshade_raytracer::renderer::environment::constant_environment::ConstantEnvironment;
+use moonshade_raytracer::renderer::image::Image;
+use moonshade_raytracer::renderer::lens_shader::plane_orthographic_lens_shader::PlaneOrthographicalLensShader;
+use moonshade_raytracer::renderer::pixel_shader::default_pixel_shader::DefaultPixelShader;
+use moonshade_raytracer::renderer::ray_shader::default_ray_shader::DefaultRayShader;
+use moonshade_raytracer::renderer::renderer::Renderer;
+use moonshade_raytracer::surfaces::material::Material;
+use moonshade_raytracer::surfaces::sphere::Sphere;
+
+#[test]
+fn renders_with_a_single_thread() {
+    let (sender, receiver) = channel();
+
+    thread::spawn(move || {
+        let lens_shader = PlaneOrthographicalLensShader::new(Vector::new(4.0, 4.0, 1.0));
+        let mut renderer = Renderer::new(lens_shader, DefaultRayShader::new(2), DefaultPixelShader::new(ConstantEnvironment::new(Color::BLUE)));
+        renderer.set_thread_count(1);
+
+        let mut image: Image = Image::new(8, 8);
+        renderer.render(&mut image, &Sphere::new(1.0, Material::new(Color::RED)));
+        sender.send(image).unwrap();
+    });
+
+    let image = receiver.recv_timeout(Duration::from_secs(30)).expect("rendering with one thread did not finish");
+    assert!(image.color_at(4, 4).is_some());
+}
@@ -0,0 +1,32 @@
+use moonshade_raytracer::scene::scene::Scene;
+use moonshade_raytracer::scene::scene_exporter::SceneExporter;
+use moonshade_raytracer::scene::statement::Statement;
+
+const SOURCE: &str = "
+environment sky { sun 0 1 0; turbidity 3; intensity 0.01 }
+
+geometry pearl {
+    sphere { radius 0.5; material { diffuse 0.9 0.9 0.9 } }
+    sphere { radius 0.25; translate 0 1 0 }
+}
+
+instance pearl { translate 2 0 0 }
+instance pearl { translate -2 0 0; material { diffuse 1 0 0 } }
+";
+
+fn exported(scene: &Scene) -> String {
+    let mut exporter = SceneExporter::new();
+    let source = Statement::format_all(&exporter.scene(scene));
+
+    assert!(exporter.warnings().is_empty(), "{:?}", exporter.warnings());
+    source
+}
+
+#[test]
+fn exported_scene_loads_back_unchanged() {
+    let source = exported(&Scene::parse(SOURCE, ".").unwrap());
+
+    assert!(source.contains("geometry geometry_1"));
+    assert!(source.contains("environment sky"));
+    assert_eq!(exported(&Scene::parse(&source, ".").unwrap()), source);
+}
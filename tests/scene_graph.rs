@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use moonshade_raytracer::math::color::Color;
+use moonshade_raytracer::math::point::Point;
+use moonshade_raytracer::math::ray::Ray;
+use moonshade_raytracer::math::transformation::transform::Transform;
+use moonshade_raytracer::math::vector::Vector;
+use moonshade_raytracer::scene::scene_graph::{SceneGraph, SceneNode, SharedSurface};
+use moonshade_raytracer::surfaces::material::Material;
+use moonshade_raytracer::surfaces::sphere::Sphere;
+use moonshade_raytracer::surfaces::surface::Surface;
+use moonshade_raytracer::surfaces::transformed_surface::TransformedSurface;
+
+fn offset(index: usize) -> Vector {
+    Vector::new((index % 10) as f64 * 3.0 - 13.5, (index / 10 % 10) as f64 * 3.0 - 13.5, (index / 100) as f64 * 3.0 + 10.0)
+}
+
+fn rays() -> Vec<Ray> {
+    (0..400).map(|index| {
+        let (x, y) = ((index % 20) as f64 / 19.0 - 0.5, (index / 20) as f64 / 19.0 - 0.5);
+        Ray::new(Point::new(x * 0.3, y * 0.3, 0.0), Vector::new(x, y, 1.0))
+    }).collect()
+}
+
+#[test]
+fn instanced_graph_matches_testing_every_instance() {
+    let geometry: SharedSurface = Arc::new(Sphere::new(1.0, Material::new(Color::WHITE)));
+    let mut graph = SceneGraph::new();
+
+    for index in 0..1000 {
+        graph.add(SceneNode::instance(geometry.clone()).transformed(Transform::translation(offset(index))));
+    }
+
+    let surface = graph.surface();
+    let instances = (0..1000)
+        .map(|index| TransformedSurface::new(Transform::translation(offset(index)), geometry.clone()))
+        .collect::<Vec<_>>();
+
+    let rays = rays();
+    let packet_hits = surface.intersect_rays(&rays);
+    let mut hit_count = 0;
+
+    for (ray, packet_hit) in rays.iter().zip(packet_hits) {
+        let expected = instances.iter()
+            .filter_map(|instance| instance.intersect(*ray))
+            .map(|surface_point| surface_point.t)
+            .fold(None, |closest: Option<f64>, t| Some(closest.map_or(t, |closest| closest.min(t))));
+
+        let hit = surface.intersect(*ray).map(|surface_point| surface_point.t);
+        assert_eq!(hit, expected);
+        assert_eq!(packet_hit.map(|surface_point| surface_point.t), expected);
+        hit_count += hit.is_some() as usize;
+    }
+
+    assert!(hit_count > 100);
+    assert!(surface.bounds().is_some());
+}
@@ -0,0 +1,46 @@
+use moonshade_raytracer::math::color::Color;
+use moonshade_raytracer::math::point::Point;
+use moonshade_raytracer::math::ray::Ray;
+use moonshade_raytracer::math::transformation::quaternion::Quaternion;
+use moonshade_raytracer::math::transformation::transform::Transform;
+use moonshade_raytracer::math::transformation::transformation::Transformation;
+use moonshade_raytracer::math::vector::Vector;
+use moonshade_raytracer::scene::scene::Scene;
+use moonshade_raytracer::surfaces::material::Material;
+use moonshade_raytracer::surfaces::sphere::Sphere;
+use moonshade_raytracer::surfaces::surface::Surface;
+use moonshade_raytracer::surfaces::transformed_surface::TransformedSurface;
+
+fn rotated_sphere(axis: &str) -> Scene {
+    let source = format!("sphere {{ radius 0.5; translate 2 1 3; rotate {} 30 }}", axis);
+    Scene::parse(&source, ".").unwrap()
+}
+
+#[test]
+fn scene_rotation_matches_quaternion_rotation() {
+    for (name, axis) in [("x", Vector::RIGHT), ("y", Vector::UP), ("z", Vector::FORWARD)] {
+        let world = rotated_sphere(name).world();
+        let transform = Transform::orientation(Quaternion::from_axis_angle(axis, 30.0_f64.to_radians())) * Transform::translation(Vector::new(2.0, 1.0, 3.0));
+        let expected = TransformedSurface::new(transform, Sphere::new(0.5, Material::new(Color::WHITE)));
+        let center = transform.matrix() * Point::ZERO;
+
+        for index in 0..100 {
+            let (x, y) = ((index % 10) as f64 * 0.1 - 0.45, (index / 10) as f64 * 0.1 - 0.45);
+            let ray: Ray = Ray::new(center + Vector::new(x, y, -10.0), Vector::FORWARD);
+
+            match (world.intersect(ray), expected.intersect(ray)) {
+                (Some(a), Some(b)) => assert!((a.t - b.t).abs() < 1e-9),
+                (None, None) => {}
+                _ => panic!("rotate {} disagrees with the quaternion rotation", name)
+            }
+        }
+    }
+}
+
+#[test]
+fn positive_rotation_turns_counterclockwise() {
+    let world = Scene::parse("sphere { radius 0.5; translate 2 0 0; rotate z 90 }", ".").unwrap().world();
+
+    assert!(world.intersect(Ray::new(Point::new(0.0, 2.0, -10.0), Vector::FORWARD)).is_some());
+    assert!(world.intersect(Ray::new(Point::new(2.0, 0.0, -10.0), Vector::FORWARD)).is_none());
+}
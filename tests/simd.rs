@@ -0,0 +1,111 @@
+use std::array;
+use moonshade_raytracer::math::color::Color;
+use moonshade_raytracer::math::point::Point;
+use moonshade_raytracer::math::ray::Ray;
+use moonshade_raytracer::math::ray_packet::RayPacket;
+use moonshade_raytracer::math::simd::SimdLevel;
+use moonshade_raytracer::math::transformation::transform::Transform;
+use moonshade_raytracer::math::vector::Vector;
+use moonshade_raytracer::surfaces::material::Material;
+use moonshade_raytracer::surfaces::mesh::Mesh;
+use moonshade_raytracer::surfaces::sphere::Sphere;
+use moonshade_raytracer::surfaces::surface::{Surface, SurfacePoint};
+use moonshade_raytracer::surfaces::transformed_surface::TransformedSurface;
+
+const LEVELS: [SimdLevel; 3] = [SimdLevel::Scalar, SimdLevel::Sse, SimdLevel::Avx];
+
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> f64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn ray(&mut self) -> Ray {
+        let origin = Point::new(self.next() * 4.0 - 2.0, self.next() * 4.0 - 2.0, -6.0);
+        let target = Point::new(self.next() * 3.0 - 1.5, self.next() * 3.0 - 1.5, self.next() * 2.0 - 1.0);
+        Ray::new(origin, (target - origin) * (0.5 + self.next()))
+    }
+}
+
+fn sphere_mesh() -> Mesh {
+    let (rings, segments) = (12, 24);
+    let positions = (0..=rings)
+        .flat_map(|ring| (0..segments).map(move |segment| (ring, segment)))
+        .map(|(ring, segment)| {
+            let (theta, phi) = (std::f64::consts::PI * ring as f64 / rings as f64, std::f64::consts::TAU * segment as f64 / segments as f64);
+            Point::new(theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin())
+        })
+        .collect();
+
+    let triangles = (0..rings)
+        .flat_map(|ring| (0..segments).flat_map(move |segment| {
+            let index = |ring: usize, segment: usize| ring * segments + segment % segments;
+            [[index(ring, segment), index(ring + 1, segment), index(ring + 1, segment + 1)], [index(ring, segment), index(ring + 1, segment + 1), index(ring, segment + 1)]]
+        }))
+        .collect();
+
+    Mesh::new(positions, None, None, triangles, Material::new(Color::WHITE)).unwrap()
+}
+
+fn assert_same(expected: &Option<SurfacePoint>, actual: &Option<SurfacePoint>) {
+    match (expected, actual) {
+        (Some(expected), Some(actual)) => {
+            let close = |a: f64, b: f64| (a - b).abs() <= 1e-9 * a.abs().max(b.abs()).max(1.0);
+
+            assert!(close(expected.t, actual.t), "t {} != {}", expected.t, actual.t);
+            assert!(close(expected.point.x, actual.point.x) && close(expected.point.y, actual.point.y) && close(expected.point.z, actual.point.z));
+            assert!(Vector::dot(expected.normal.vector(), actual.normal.vector()) > 1.0 - 1e-9);
+        }
+        (None, None) => {}
+        _ => panic!("packet and scalar paths disagree on a hit")
+    }
+}
+
+fn check<S: Surface>(surface: &S) {
+    let mut random = Random(7);
+    let rays = (0..512).map(|_| random.ray()).collect::<Vec<Ray>>();
+    let expected = rays.iter().map(|&ray| surface.intersect(ray)).collect::<Vec<_>>();
+    assert!(expected.iter().filter(|hit| hit.is_some()).count() > 100);
+
+    for level in LEVELS {
+        SimdLevel::limited(level, || {
+            for (chunk, expected) in rays.chunks(8).zip(expected.chunks(8)) {
+                let packet8 = surface.intersect_packet8(&RayPacket::new(array::from_fn(|lane| chunk[lane])));
+                let packet4 = [&chunk[..4], &chunk[4..]].map(|half| surface.intersect_packet4(&RayPacket::new(array::from_fn(|lane| half[lane]))));
+
+                for lane in 0..8 {
+                    assert_same(&expected[lane], &packet8[lane]);
+                    assert_same(&expected[lane], &packet4[lane / 4][lane % 4]);
+                }
+            }
+
+            for (expected, actual) in expected.iter().zip(surface.intersect_rays(&rays).iter()) {
+                assert_same(expected, actual);
+            }
+        });
+    }
+}
+
+#[test]
+fn limited_level_never_exceeds_the_detected_level() {
+    for level in LEVELS {
+        assert_eq!(SimdLevel::limited(level, SimdLevel::current), level.min(SimdLevel::detected()));
+    }
+
+    assert_eq!(SimdLevel::limited(SimdLevel::Scalar, || SimdLevel::current().packet_width()), 1);
+    assert_eq!(SimdLevel::current(), SimdLevel::detected());
+}
+
+#[test]
+fn sphere_packets_match_scalar_intersection() {
+    check(&Sphere::new(1.5, Material::new(Color::WHITE)));
+    check(&TransformedSurface::new(Transform::scale(Vector::new(1.0, 0.5, 2.0)), Sphere::new(1.0, Material::new(Color::WHITE))));
+}
+
+#[test]
+fn bvh_packets_match_scalar_intersection() {
+    check(&sphere_mesh());
+    check(&TransformedSurface::new(Transform::translation(Vector::new(0.2, -0.1, 0.5)), sphere_mesh()));
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use moonshade_raytracer::math::color::Color;
+use moonshade_raytracer::math::point::Point;
+use moonshade_raytracer::math::ray::Ray;
+use moonshade_raytracer::math::transformation::animated_transform::AnimatedTransform;
+use moonshade_raytracer::math::transformation::transform::Transform;
+use moonshade_raytracer::math::vector::Vector;
+use moonshade_raytracer::surfaces::material::Material;
+use moonshade_raytracer::surfaces::sphere::Sphere;
+use moonshade_raytracer::surfaces::surface::Surface;
+use moonshade_raytracer::surfaces::transformed_surface::TransformedSurface;
+use moonshade_raytracer::textures::marble_texture::MarbleTexture;
+use moonshade_raytracer::textures::texture::Texture;
+use moonshade_raytracer::textures::wood_texture::WoodTexture;
+
+fn textures() -> Vec<Arc<dyn Texture + Send + Sync>> {
+    vec![
+        Arc::new(MarbleTexture::new(Color::WHITE, Color::BLACK, Vector::RIGHT, 3.0, 2.0)),
+        Arc::new(WoodTexture::new(Color::WHITE, Color::BLACK, Vector::UP, 4.0, 0.5))
+    ]
+}
+
+fn sphere() -> Arc<Sphere> {
+    Arc::new(Sphere::new(1.0, Material::new(Color::WHITE)))
+}
+
+fn same_color(a: Color, b: Color) -> bool {
+    (a.red - b.red).abs() < 1e-9 && (a.green - b.green).abs() < 1e-9 && (a.blue - b.blue).abs() < 1e-9
+}
+
+#[test]
+fn solid_textures_follow_instances() {
+    let offset = Vector::new(5.0, -2.0, 3.0);
+    let first = TransformedSurface::new(Transform::translation(Vector::ZERO), sphere());
+    let second = TransformedSurface::new(Transform::translation(offset), sphere());
+
+    for index in 0..50 {
+        let direction = Vector::new(index as f64 * 0.003 - 0.075, 0.05 - index as f64 * 0.002, 1.0);
+        let origin = Point::new(0.0, 0.0, -5.0);
+
+        let a = first.intersect(Ray::new(origin, direction)).unwrap();
+        let b = second.intersect(Ray::new(origin + offset, direction)).unwrap();
+
+        for texture in textures() {
+            assert!(same_color(texture.color(&a), texture.color(&b)));
+        }
+    }
+}
+
+#[test]
+fn solid_textures_move_with_animated_objects() {
+    let offset = Vector::new(0.0, 0.0, 4.0);
+    let animation = AnimatedTransform::between(Transform::translation(Vector::ZERO), Transform::translation(offset));
+    let moving = TransformedSurface::animated(animation, sphere());
+
+    let ray = Ray::new(Point::new(0.3, 0.2, -5.0), Vector::FORWARD);
+    let start = moving.intersect(ray.with_time(0.0)).unwrap();
+    let end = moving.intersect(ray.with_time(1.0)).unwrap();
+
+    assert!(Point::distance(start.point, end.point) > 3.0);
+
+    for texture in textures() {
+        assert!(same_color(texture.color(&start), texture.color(&end)));
+    }
+}